@@ -1,23 +1,76 @@
 //!  MIT license.
 //!
-//!  Given a list of EnsEMBL identifiers, return a CSV file of EnsEMBL entries.
+//!  Given a list of EnsEMBL identifiers, or of gene symbols, return a CSV file of EnsEMBL entries.
+
+use serde::ser::SerializeStruct;
 
 #[actix_web::main]
 async fn main() {
     //  Get arguments from the command line.
     let argument_matches = cli().get_matches();
 
+    //  If quota is set, write the requests remaining today (UTC) of each service's daily limit to standard output and exit.
+    if argument_matches.get_flag("quota") {
+        print_quota();
+        return;
+    }
+
+    //  Assign the cache arguments passed in from the command line to a variable.
+    //  If a cache directory is supplied, responses are served from and stored in the cache; offline, only cached responses are served.
+    if let Some(directory) = argument_matches.get_one::<String>("cache") {
+        let ttl = match argument_matches.get_one::<String>("cache_ttl").map(|ttl| ttl.parse::<u64>()) {
+            Some(Ok(ttl)) => std::time::Duration::from_secs(ttl),
+            Some(Err(error)) => {
+                eprintln!("{{\"argument\": \"cache_ttl\", \"error\": \"{}\"}}", error);
+                std::process::exit(1);
+            }
+            None => unreachable!("default_value prevents `None`."),
+        };
+        let cache = utils::cache::HttpCache::new(std::path::Path::new(directory))
+            .with_ttl(ttl)
+            .with_offline(argument_matches.get_flag("offline"));
+        if utils::cache::configure(cache).is_err() {
+            unreachable!("the cache is only configured here.");
+        }
+    }
+
     //  Assign certificate arguments passed in from the command line to a variable.
-    //  Certificate authority certificates are required, there should always be at least one certificate string present.
-    let client = match argument_matches.get_many::<String>("certificates") {
-        Some(certificate_files) => {
+    //  Certificate authority certificates are trusted along with the root certificates chosen; without either, the system's trust store is trusted.
+    let certificate_files: Vec<&std::path::Path> = match argument_matches.get_many::<String>("certificates") {
+        Some(certificate_files) => certificate_files.map(|cert| std::path::Path::new(&cert[..])).collect(),
+        None => Vec::new(),
+    };
+    let root_certificates = match argument_matches.get_one::<String>("root_certificates").map(|source| source.parse::<utils::web::RootCertificates>()) {
+        Some(Ok(root_certificates)) => root_certificates,
+        Some(Err(error)) => {
+            eprintln!("{{\"error\": \"{}\"}}", error);
+            std::process::exit(1);
+        }
+        None if certificate_files.is_empty() => utils::web::RootCertificates::System,
+        None => utils::web::RootCertificates::None,
+    };
+    let mut tls_config = certificate_files
+        .iter()
+        .fold(utils::web::TlsConfig::new(), |config, certificate_file| config.with_certificate_file(certificate_file))
+        .with_root_certificates(root_certificates);
 
-            let paths: Vec<&std::path::Path> = certificate_files.map(|cert| std::path::Path::new(&cert[..])).collect();
-            utils::web::new_client(paths)
-        },
-        _ => unreachable!("required(true) prevents `None`."),
+    //  Assign the client certificate and key passed in from the command line to a variable, to authenticate to servers that require it (mutual TLS).
+    if let (Some(client_certificate), Some(client_key)) = (argument_matches.get_one::<String>("client_certificate"), argument_matches.get_one::<String>("client_key")) {
+        tls_config = tls_config.with_client_certificate(std::path::Path::new(client_certificate), std::path::Path::new(client_key));
+    }
+
+    //  Exit with an error code (1) if a certificate, key or trust store cannot be used.
+    let client = match tls_config.client() {
+        Ok(client) => client,
+        Err(error) => {
+            eprintln!("{{\"error\": \"{}\"}}", error);
+            std::process::exit(1);
+        }
     };
 
+    //  Create the UCSC Genome Browser that the DNA of genes is fetched from by default.
+    let ucsc = utils::genome_browser::ucsc::UcscGenomeBrowser::new(client.clone());
+
     //  Assign the input file argument passed in from the command line to a variable.
     //  The input file argument is required, there should always be an input filename present as an argument because of the clap crate's required(true) implementation.
     let file: &std::path::Path = match argument_matches.get_one::<String>("file") {
@@ -48,6 +101,64 @@ async fn main() {
         std::process::exit(1);
     }
 
+    //  Assign the optional FASTA output filename argument passed in from the command line to a variable.
+    //  Exit with an error code (1) if the FASTA output filename already exists.
+    let fasta_file = argument_matches.get_one::<String>("fasta");
+    if let Some(fasta_file) = fasta_file {
+        if std::path::Path::new(fasta_file).exists() {
+            eprintln!("{{\"file\": \"{}\", \"error\": \"File already exists! Please provide a filename that does not exist.\"}}", fasta_file);
+            std::process::exit(1);
+        }
+    }
+
+    //  Assign the sequence arguments passed in from the command line to a variable.
+    //  Sequences are fetched from the UCSC Genome Browser by default, or from EnsEMBL with a sequence type, mask and flanking expansion.
+    let sequence_source = match argument_matches.get_one::<String>("sequence_source").map(|source| &source[..]) {
+        Some("ensembl") => {
+            let options = parse_sequence_options(&argument_matches);
+            //  Only genomic sequences are gene DNA, other sequences are written to the FASTA file alone.
+            if options.sequence_type != utils::genome_browser::SequenceType::Genomic && fasta_file.is_none() {
+                eprintln!("{{\"error\": \"A --sequence-type of {} requires a --fasta output file.\"}}", options.sequence_type);
+                std::process::exit(1);
+            }
+            utils::genome_browser::SequenceSource::Ensembl(options)
+        },
+        Some(_) => {
+            if ["sequence_type", "mask", "expand_5prime", "expand_3prime"].iter().any(|id| argument_matches.value_source(id) == Some(clap::parser::ValueSource::CommandLine)) {
+                eprintln!("{{\"error\": \"--sequence-type, --mask, --expand-5prime and --expand-3prime require --sequence-source ensembl.\"}}");
+                std::process::exit(1);
+            }
+            utils::genome_browser::SequenceSource::Region(&ucsc)
+        },
+        None => unreachable!("default_value prevents `None`."),
+    };
+    let writes_gene_dna = !matches!(&sequence_source, utils::genome_browser::SequenceSource::Ensembl(options) if options.sequence_type != utils::genome_browser::SequenceType::Genomic);
+
+    //  Assign the flag value of statistics to a variable.
+    //  If statistics is set, each row is followed by columns of the entry's sequence statistics.
+    let include_statistics = argument_matches.get_flag("statistics");
+
+    //  Assign the flag value of by_symbol and the species argument to variables.
+    //  If by_symbol is set, the identifiers are gene symbols of the species, which defaults to homo_sapiens.
+    let by_symbol = argument_matches.get_flag("by_symbol");
+    let species = match argument_matches.get_one::<String>("species") {
+        Some(species) => species,
+        _ => unreachable!("default_value prevents `None`."),
+    };
+
+    //  Create the EnsEMBL genome browser of the species that genes are looked up at.
+    let ensembl = utils::genome_browser::ensembl::EnsemblGenomeBrowser::new(client).with_species(species);
+
+    //  Assign the external databases passed in from the command line to a variable.
+    //  Each database adds a column of the entry's identifiers in the database.
+    let xref_databases: Vec<utils::xref::ExternalDatabase> = match argument_matches.get_many::<String>("xrefs") {
+        Some(databases) => databases.map(|database| match database.parse() {
+            Ok(database) => database,
+            Err(error) => match error {},
+        }).collect(),
+        None => Vec::new(),
+    };
+
     //  Assign the flag value of no_headers to a variable.
     //  If no_headers is not set, no_headers will default to false, and this, by default, implies the input file has headers.
     let has_headers = !argument_matches.get_flag("no_headers");
@@ -87,6 +198,18 @@ async fn main() {
         }
     };
 
+    //  Create a FASTA writer for the fetched DNA if a FASTA output filename is supplied.
+    let mut fasta_writer = match fasta_file {
+        Some(fasta_file) => match std::fs::File::create(fasta_file) {
+            Ok(file) => Some(utils::seq::io::FastaWriter::new(std::io::BufWriter::new(file))),
+            Err(error) => {
+                eprintln!("{{\"file\": \"{}\", \"error\": \"{}\"}}", fasta_file, error);
+                std::process::exit(1);
+            }
+        },
+        None => None,
+    };
+
     //  Read the file and extract identifiers from the column defined by the 'index' argument.
     //  If an error occurs then exit with an exit code (1) and output the error to standard error.
     let identifiers: std::collections::HashSet<String> =
@@ -98,40 +221,56 @@ async fn main() {
             }
         };
 
-    //  Track the number of requests made and the time the last request was made.
-    let mut number_of_requests_made: u32 = 0;
-    let mut timestamp: Option<std::time::SystemTime> = None;
-
-    //  For each EnsEMBL identifer,
-    for identifier in identifiers {
-        //  Search the genome browsers.
-        match utils::genome_browser::ensembl_search(
-            &client,
-            &identifier,
-            true,
-            timestamp,
-            Some(number_of_requests_made),
-        )
-        .await
-        {
-            Ok(genome_browser_response) => {
-                number_of_requests_made += 1;
-                timestamp = Some(genome_browser_response.timestamp);
-
-                //  Write the result as a CSV row.
-                let serialized_result = csv_writer.serialize(genome_browser_response.gene);
-
-                if serialized_result.is_err() {
-                    eprintln!(
-                        "{{\"id\": \"{}\", \"error\": \"{:#?}\"}}",
-                        identifier,
-                        serialized_result.err()
-                    );
+    //  Order the identifiers so that batches, and the rows written, are reproducible.
+    let mut identifiers: Vec<String> = identifiers.into_iter().collect();
+    identifiers.sort();
+
+    //  For each gene symbol, resolve the symbol to EnsEMBL genes and write every gene found.
+    if by_symbol {
+        for symbol in identifiers.iter() {
+            let resolution = match utils::genome_browser::ensembl_symbol_search(&ensembl, symbol, Some(&sequence_source)).await {
+                Ok(resolution) => resolution,
+                Err(error) => {
+                    eprintln!("{{\"symbol\": \"{}\", \"error\": \"{}\"}}", symbol, error);
+                    continue;
                 }
+            };
+
+            //  Report symbols that resolve to more than one gene.
+            if let Some(report) = resolution.ambiguity_report() {
+                eprintln!("{}", report);
             }
-            Err(error) => {
-                eprintln!("{{\"id\": \"{}\", \"error\": \"{}\"}}", identifier, error);
+
+            for gene in resolution.genes {
+                let gene = with_xrefs(&ensembl, gene, &xref_databases).await;
+                write_gene(&mut csv_writer, fasta_writer.as_mut().filter(|_| writes_gene_dna), symbol, &gene, include_statistics, &xref_databases);
             }
+            write_sequences(fasta_writer.as_mut().filter(|_| !writes_gene_dna), &resolution.sequences);
+        }
+    } else {
+        //  For each batch of up to 1,000 EnsEMBL identifiers,
+        for batch in identifiers.chunks(utils::genome_browser::ENSEMBL_LOOKUP_BATCH_SIZE) {
+            //  Search the genome browsers.
+            let batch_response = match utils::genome_browser::ensembl_batch_search(&ensembl, batch, Some(&sequence_source)).await {
+                Ok(batch_response) => batch_response,
+                Err(error) => {
+                    for identifier in batch {
+                        eprintln!("{{\"id\": \"{}\", \"error\": \"{}\"}}", identifier, error);
+                    }
+                    continue;
+                }
+            };
+
+            //  Report the identifiers EnsEMBL could not resolve.
+            for identifier in batch_response.unresolved.iter() {
+                eprintln!("{{\"id\": \"{}\", \"error\": \"EnsEMBL could not resolve the identifier.\"}}", identifier);
+            }
+
+            for (identifier, gene) in batch_response.genes {
+                let gene = with_xrefs(&ensembl, gene, &xref_databases).await;
+                write_gene(&mut csv_writer, fasta_writer.as_mut().filter(|_| writes_gene_dna), &identifier, &gene, include_statistics, &xref_databases);
+            }
+            write_sequences(fasta_writer.as_mut().filter(|_| !writes_gene_dna), &batch_response.sequences);
         }
     }
 
@@ -140,30 +279,246 @@ async fn main() {
     if csv_writer_flush_result.is_err() {
         eprintln!("{{\"error\": \"{:#?}\"}}", csv_writer_flush_result.err());
     }
+
+    //  Flush the FASTA writer before exiting.
+    if let Some(Err(error)) = fasta_writer.as_mut().map(|fasta_writer| fasta_writer.flush()) {
+        eprintln!("{{\"error\": \"{}\"}}", error);
+    }
+
+    //  Report the responses served from, and stored in, the cache.
+    if let Some(cache) = utils::cache::global() {
+        let statistics = cache.statistics();
+        eprintln!(
+            "{{\"cache\": \"{}\", \"hits\": {}, \"misses\": {}, \"expired\": {}, \"stores\": {}, \"hit_rate\": {:.3}}}",
+            cache.get_directory().display(), statistics.hits, statistics.misses, statistics.expired, statistics.stores, statistics.hit_rate()
+        );
+    }
+}
+
+///  Write the requests made today (UTC) to each service, and the requests remaining of EnsEMBL's daily limit, as JSON lines.
+///  Exit with an error code (1) if the quota ledger cannot be read.
+fn print_quota() {
+    let ledger = utils::quota::global();
+    let date = utils::time::utc_date(std::time::SystemTime::now());
+    for (service, limit) in [(utils::quota::ENSEMBL, Some(utils::quota::ENSEMBL_DAILY_LIMIT)), (utils::quota::UCSC, None)] {
+        let used = match ledger.used(service) {
+            Ok(used) => used,
+            Err(error) => {
+                eprintln!("{{\"file\": \"{}\", \"error\": \"{}\"}}", ledger.get_path().display(), error);
+                std::process::exit(1);
+            }
+        };
+        match limit {
+            Some(limit) => println!(
+                "{{\"service\": \"{}\", \"date\": \"{}\", \"used\": {}, \"limit\": {}, \"remaining\": {}}}",
+                service, date, used, limit, limit.saturating_sub(used)
+            ),
+            None => println!("{{\"service\": \"{}\", \"date\": \"{}\", \"used\": {}}}", service, date, used),
+        }
+    }
+}
+
+///  Parse the EnsEMBL sequence options passed in from the command line, exiting with an error code (1) if an option is invalid.
+fn parse_sequence_options(argument_matches: &clap::ArgMatches) -> utils::genome_browser::EnsemblSequenceOptions {
+    let parse_expansion = |id: &str| -> u64 {
+        match argument_matches.get_one::<String>(id).map(|expansion| expansion.parse::<u64>()) {
+            Some(Ok(expansion)) => expansion,
+            Some(Err(error)) => {
+                eprintln!("{{\"argument\": \"{}\", \"error\": \"{}\"}}", id, error);
+                std::process::exit(1);
+            }
+            None => unreachable!("default_value prevents `None`."),
+        }
+    };
+
+    utils::genome_browser::EnsemblSequenceOptions {
+        sequence_type: match argument_matches.get_one::<String>("sequence_type").map(|sequence_type| sequence_type.parse()) {
+            Some(Ok(sequence_type)) => sequence_type,
+            Some(Err(error)) => {
+                eprintln!("{{\"error\": \"{}\"}}", error);
+                std::process::exit(1);
+            }
+            None => unreachable!("default_value prevents `None`."),
+        },
+        mask: match argument_matches.get_one::<String>("mask").map(|mask| mask.parse()) {
+            Some(Ok(mask)) => Some(mask),
+            Some(Err(error)) => {
+                eprintln!("{{\"error\": \"{}\"}}", error);
+                std::process::exit(1);
+            }
+            None => None,
+        },
+        expand_5prime: parse_expansion("expand_5prime"),
+        expand_3prime: parse_expansion("expand_3prime"),
+    }
+}
+
+///  Write sequences fetched from EnsEMBL as FASTA records, if a FASTA writer is supplied, reporting errors with the identifier they were requested by.
+fn write_sequences(
+    fasta_writer: Option<&mut utils::seq::io::FastaWriter<std::io::BufWriter<std::fs::File>>>,
+    sequences: &[utils::genome_browser::EnsemblSequence],
+) {
+    if let Some(fasta_writer) = fasta_writer {
+        for sequence in sequences {
+            if let Err(error) = fasta_writer.write_record(&sequence.to_fasta_record()) {
+                eprintln!("{{\"id\": \"{}\", \"error\": \"{}\"}}", sequence.get_query(), error);
+            }
+        }
+    }
+}
+
+///  Retrieve the gene's cross-references to the external databases, if any databases are selected.
+///  The gene is returned without cross-references if they cannot be retrieved.
+async fn with_xrefs(
+    ensembl: &utils::genome_browser::ensembl::EnsemblGenomeBrowser,
+    gene: utils::gene::Gene,
+    xref_databases: &[utils::xref::ExternalDatabase],
+) -> utils::gene::Gene {
+    if xref_databases.is_empty() {
+        return gene;
+    }
+
+    let response = utils::genome_browser::ensembl_xrefs_search(ensembl, gene.get_id(), xref_databases).await;
+
+    match response {
+        Ok(response) => gene.with_xrefs(response.xrefs),
+        Err(error) => {
+            eprintln!("{{\"id\": \"{}\", \"error\": \"{}\"}}", gene.get_id(), error);
+            gene
+        }
+    }
+}
+
+///  Write a gene as a CSV row, and its DNA as a FASTA record if a FASTA writer is supplied, reporting errors with the identifier it was found by.
+fn write_gene(
+    csv_writer: &mut csv::Writer<std::fs::File>,
+    fasta_writer: Option<&mut utils::seq::io::FastaWriter<std::io::BufWriter<std::fs::File>>>,
+    identifier: &str,
+    gene: &utils::gene::Gene,
+    include_statistics: bool,
+    xref_databases: &[utils::xref::ExternalDatabase],
+) {
+    //  Write the gene's DNA as a FASTA record.
+    if let Some(fasta_writer) = fasta_writer {
+        if let Err(error) = fasta_writer.write_record(&gene.to_fasta_record()) {
+            eprintln!("{{\"id\": \"{}\", \"error\": \"{}\"}}", identifier, error);
+        }
+    }
+
+    //  Write the result as a CSV row.
+    let row = GeneRow {
+        gene,
+        statistics: include_statistics.then(|| gene.statistics()),
+        xref_databases,
+    };
+    let serialized_result = csv_writer.serialize(row);
+
+    if serialized_result.is_err() {
+        eprintln!(
+            "{{\"id\": \"{}\", \"error\": \"{:#?}\"}}",
+            identifier,
+            serialized_result.err()
+        );
+    }
+}
+
+///  A CSV row of an EnsEMBL entry, optionally followed by the sequence statistics of its DNA and its identifiers in external databases.
+struct GeneRow<'a> {
+    gene: &'a utils::gene::Gene,
+    statistics: Option<utils::seq::statistics::SequenceStatistics>,
+    xref_databases: &'a [utils::xref::ExternalDatabase],
+}
+
+impl serde::ser::Serialize for GeneRow<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        let mut serialized_row = serializer.serialize_struct("GeneRow", 24)?;
+        self.gene.serialize_fields(&mut serialized_row)?;
+        if let Some(statistics) = &self.statistics {
+            statistics.serialize_fields(&mut serialized_row)?;
+        }
+        //  Identifiers are separated by semicolons, as a gene may have several in a database, such as several UniProt entries.
+        for database in self.xref_databases {
+            if let Some(column_name) = database.get_column_name() {
+                serialized_row.serialize_field(column_name, &self.gene.get_xref_ids(database).join(";"))?;
+            }
+        }
+        serialized_row.end()
+    }
 }
 
 ///  Command line arguments for the EnsEMBL sequence finder program.
 ///
 ///  Options:
-///  -c, --certificate <certificates> (required)  A DER-encoded X.509 file
+///  -c, --certificate <certificates>             A PEM or DER encoded X.509 certificate file of a trusted certificate authority
+///  -r, --root-certificates <source>             The root certificates to trust as well as the certificate files (none, system, webpki) [default: system without --certificate, otherwise none]
+///      --client-certificate <file>              A PEM or DER encoded X.509 certificate chain to authenticate the client with (mutual TLS); requires --client-key
+///      --client-key <file>                      The PEM or DER encoded private key of the client certificate; requires --client-certificate
+///  -C, --cache <directory>                      The directory to cache responses in, reusing them on later runs
+///  -T, --cache-ttl <seconds>                    The number of seconds cached responses are reused for [default: 86400]
+///  -b, --by-symbol                              A flag that indicates the values are gene symbols (e.g. HGNC symbols) rather than EnsEMBL identifiers
 ///  -i, --index <index>              (required)  A column index to take the set of values.
 ///  -d, --delimiter <delimiter>                  The delimiter character that separates each field value (e.g. ',', ';', '\t')
 ///  -f, --file <file>                (required)  The flat file (e.g. CSV, TSV) file path to parse for identifiers
+///  -a, --fasta <fasta>                          The output file name and path to write the DNA of each entry as a FASTA file
+///  -m, --mask <mask>                            The masking of repeats in EnsEMBL genomic sequences (soft, hard)
 ///  -n, --no-headers                             A flag that indicates no header row is present
+///  -o, --offline                                A flag that serves only cached responses, without making requests; requires --cache
 ///  -O, --output <output>            (required)  The output file name and path to write a CSV file
+///  -q, --sequence-source <source>               Where to fetch sequences from (ucsc, ensembl) [default: ucsc]
+///  -Q, --quota                                  A flag that writes the requests remaining today (UTC) of each service's daily limit and exits
+///  -t, --sequence-type <type>                   The type of EnsEMBL sequence (genomic, cdna, cds, protein) [default: genomic]
+///  -5, --expand-5prime <bases>                  The number of bases to extend EnsEMBL genomic sequences upstream [default: 0]
+///  -3, --expand-3prime <bases>                  The number of bases to extend EnsEMBL genomic sequences downstream [default: 0]
+///  -S, --species <species>                     The species of gene symbols (e.g. homo_sapiens, mus_musculus) [default: homo_sapiens]
+///  -s, --statistics                             A flag that adds sequence statistics columns (GC content, GC skew, CpG observed/expected, N content, soft-masked fraction, longest homopolymer)
+///  -x, --xrefs <databases>                     A comma separated list of external databases to add columns of identifiers for (hgnc, ncbi_gene_id, omim, uniprot_swissprot, uniprot_trembl, refseq_mrna, refseq_peptide)
 ///  -h, --help                                   Print help information
 ///  -V, --version                                Print version information
 fn cli() -> clap::Command {
     clap::Command::new("ensembl_sequence")
     .version("0.1.0")
     .author("Nathan Edwards <Nathan.W.Edwards@Outlook.com>")
-    .about("Given a flat file with EnsEMBL identifiers at the specified index and valid certificates for *.ensembl.org and genome.ucsc.edu, generate a CSV file of EnsEMBL entries")
+    .about("Given a flat file with EnsEMBL identifiers, or gene symbols, at the specified index and valid certificates for *.ensembl.org and genome.ucsc.edu, generate a CSV file of EnsEMBL entries")
     .arg(clap::Arg::new("certificates")
         .short('c')
         .long("certificate")
-        .required(true)
         .action(clap::ArgAction::Append)
-        .help("A DER-encoded X.509 file"))
+        .help("A PEM or DER encoded X.509 certificate file of a trusted certificate authority"))
+    .arg(clap::Arg::new("root_certificates")
+        .short('r')
+        .long("root-certificates")
+        .value_name("source")
+        .value_parser(["none", "system", "webpki"])
+        .help("The root certificates to trust as well as the certificate files [default: system without --certificate, otherwise none]"))
+    .arg(clap::Arg::new("client_certificate")
+        .long("client-certificate")
+        .value_name("file")
+        .requires("client_key")
+        .help("A PEM or DER encoded X.509 certificate chain to authenticate the client with (mutual TLS)"))
+    .arg(clap::Arg::new("client_key")
+        .long("client-key")
+        .value_name("file")
+        .requires("client_certificate")
+        .help("The PEM or DER encoded private key of the client certificate"))
+    .arg(clap::Arg::new("cache")
+        .short('C')
+        .long("cache")
+        .value_name("directory")
+        .help("The directory to cache responses in, reusing them on later runs"))
+    .arg(clap::Arg::new("cache_ttl")
+        .short('T')
+        .long("cache-ttl")
+        .value_name("seconds")
+        .default_value("86400")
+        .help("The number of seconds cached responses are reused for"))
+    .arg(clap::Arg::new("by_symbol")
+        .short('b')
+        .long("by-symbol")
+        .action(clap::ArgAction::SetTrue)
+        .help("A flag that indicates the values are gene symbols (e.g. HGNC symbols) rather than EnsEMBL identifiers"))
     .arg(clap::Arg::new("index")
         .required(true)
         .short('i')
@@ -178,14 +533,79 @@ fn cli() -> clap::Command {
         .short('f')
         .long("file")
         .help("The flat file (e.g. CSV, TSV) file path to parse for identifiers"))
+    .arg(clap::Arg::new("fasta")
+        .short('a')
+        .long("fasta")
+        .help("The output file name and path to write the DNA of each entry as a FASTA file"))
+    .arg(clap::Arg::new("mask")
+        .short('m')
+        .long("mask")
+        .value_parser(["soft", "hard"])
+        .help("The masking of repeats in EnsEMBL genomic sequences"))
     .arg(clap::Arg::new("no_headers")
         .short('n')
         .long("no-headers")
         .action(clap::ArgAction::SetTrue)
         .help("A flag that indicates no header row is present"))
+    .arg(clap::Arg::new("offline")
+        .short('o')
+        .long("offline")
+        .action(clap::ArgAction::SetTrue)
+        .requires("cache")
+        .help("A flag that serves only cached responses, without making requests"))
     .arg(clap::Arg::new("output")
         .short('O')
         .long("output")
         .required(true)
         .help("The output file name and path to write a CSV file"))
+    .arg(clap::Arg::new("sequence_source")
+        .short('q')
+        .long("sequence-source")
+        .value_name("source")
+        .value_parser(["ucsc", "ensembl"])
+        .default_value("ucsc")
+        .help("Where to fetch sequences from"))
+    .arg(clap::Arg::new("quota")
+        .short('Q')
+        .long("quota")
+        .action(clap::ArgAction::SetTrue)
+        .exclusive(true)
+        .help("A flag that writes the requests remaining today (UTC) of each service's daily limit and exits"))
+    .arg(clap::Arg::new("sequence_type")
+        .short('t')
+        .long("sequence-type")
+        .value_name("type")
+        .value_parser(["genomic", "cdna", "cds", "protein"])
+        .default_value("genomic")
+        .help("The type of EnsEMBL sequence; sequences other than genomic are written to the FASTA file"))
+    .arg(clap::Arg::new("expand_5prime")
+        .short('5')
+        .long("expand-5prime")
+        .value_name("bases")
+        .default_value("0")
+        .help("The number of bases to extend EnsEMBL genomic sequences upstream"))
+    .arg(clap::Arg::new("expand_3prime")
+        .short('3')
+        .long("expand-3prime")
+        .value_name("bases")
+        .default_value("0")
+        .help("The number of bases to extend EnsEMBL genomic sequences downstream"))
+    .arg(clap::Arg::new("species")
+        .short('S')
+        .long("species")
+        .default_value("homo_sapiens")
+        .help("The species of gene symbols (e.g. homo_sapiens, mus_musculus)"))
+    .arg(clap::Arg::new("statistics")
+        .short('s')
+        .long("statistics")
+        .action(clap::ArgAction::SetTrue)
+        .help("A flag that adds sequence statistics columns (GC content, GC skew, CpG observed/expected, N content, soft-masked fraction, longest homopolymer)"))
+    .arg(clap::Arg::new("xrefs")
+        .short('x')
+        .long("xrefs")
+        .value_name("databases")
+        .value_delimiter(',')
+        .action(clap::ArgAction::Append)
+        .value_parser(["hgnc", "ncbi_gene_id", "omim", "uniprot_swissprot", "uniprot_trembl", "refseq_mrna", "refseq_peptide"])
+        .help("A comma separated list of external databases to add columns of identifiers for"))
 }
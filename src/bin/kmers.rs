@@ -0,0 +1,166 @@
+//!  MIT license.
+//!
+//!  Given a FASTA file, output the counts of the k-mers in its sequences as a CSV or JSON file.
+
+fn main() {
+    //  Get arguments from the command line.
+    let argument_matches = cli().get_matches();
+
+    //  Assign the input file argument passed in from the command line to a variable.
+    //  The input file argument is required, there should always be an input filename present as an argument because of the clap crate's required(true) implementation.
+    let file: &std::path::Path = match argument_matches.get_one::<String>("file") {
+        Some(file) => std::path::Path::new(&file[..]),
+        _ => unreachable!("required(true) prevents `None`."),
+    };
+
+    //  Exit with an error code (1) if the input file does not exist.
+    if !file.exists() {
+        eprintln!(
+            "{{\"file\": \"{}\", \"error\": \"{}\"}}",
+            file.display(),
+            std::io::Error::from(std::io::ErrorKind::NotFound)
+        );
+        std::process::exit(1);
+    }
+
+    //  Assign the k-mer length argument to a variable.
+    //  The k-mer length will always be defined because of the clap crate's required(true) implementation.
+    let k = match argument_matches.get_one::<String>("k") {
+        Some(k) => match k.parse::<usize>() {
+            Ok(value) => value,
+            Err(_) => {
+                eprintln!(
+                    "{{\"error\": \"{}\"}}",
+                    std::io::Error::from(std::io::ErrorKind::InvalidInput)
+                );
+                std::process::exit(1);
+            }
+        },
+        _ => unreachable!("required(true) prevents `None`."),
+    };
+
+    //  Assign the flag values to variables.
+    let canonical = argument_matches.get_flag("canonical");
+    let spectrum = argument_matches.get_flag("spectrum");
+
+    //  Assign the output format argument to a variable.
+    //  If no format argument is supplied through the command line, output CSV.
+    let format = match argument_matches.get_one::<String>("format") {
+        Some(format) => &format[..],
+        None => "csv",
+    };
+
+    let mut counts = match utils::seq::kmer::KmerCounts::new(k, canonical) {
+        Ok(counts) => counts,
+        Err(error) => {
+            eprintln!("{{\"error\": \"{}\"}}", error);
+            std::process::exit(1);
+        }
+    };
+
+    //  Stream the FASTA file, which may be gzip compressed, and count the k-mers of each record.
+    let reader = match utils::seq::io::FastaReader::from_path(file) {
+        Ok(reader) => reader,
+        Err(error) => {
+            eprintln!("{{\"file\": \"{}\", \"error\": \"{}\"}}", file.display(), error);
+            std::process::exit(1);
+        }
+    };
+    for record in reader {
+        let record = match record {
+            Ok(record) => record,
+            Err(error) => {
+                eprintln!("{{\"file\": \"{}\", \"error\": \"{}\"}}", file.display(), error);
+                std::process::exit(1);
+            }
+        };
+        match record.get_dna_sequence() {
+            Ok(dna) => counts.add(&dna),
+            Err(error) => {
+                eprintln!("{{\"id\": \"{}\", \"error\": \"{}\"}}", record.get_id(), error);
+            }
+        }
+    }
+
+    //  Write the counts to the output file if one is supplied, otherwise to standard output.
+    let writer: Box<dyn std::io::Write> = match argument_matches.get_one::<String>("output") {
+        Some(output_file) => {
+            //  Exit with an error code (1) if the output filename already exists.
+            if std::path::Path::new(output_file).exists() {
+                eprintln!("{{\"file\": \"{}\", \"error\": \"File already exists! Please provide a filename that does not exist.\"}}", output_file);
+                std::process::exit(1);
+            }
+            match std::fs::File::create(output_file) {
+                Ok(file) => Box::new(file),
+                Err(error) => {
+                    eprintln!("{{\"file\": \"{}\", \"error\": \"{}\"}}", output_file, error);
+                    std::process::exit(1);
+                }
+            }
+        }
+        None => Box::new(std::io::stdout()),
+    };
+
+    let write_result = match (format, spectrum) {
+        ("json", _) => write_json(writer, &counts),
+        (_, true) => counts.write_spectrum_csv(writer),
+        (_, false) => counts.write_csv(writer),
+    };
+    if let Err(error) = write_result {
+        eprintln!("{{\"error\": \"{}\"}}", error);
+        std::process::exit(1);
+    }
+}
+
+///  Write the k-mer counts and spectrum as a JSON object.
+fn write_json(mut writer: Box<dyn std::io::Write>, counts: &utils::seq::kmer::KmerCounts) -> Result<(), Box<dyn std::error::Error>> {
+    writeln!(writer, "{}", counts.to_json()?)?;
+    Ok(())
+}
+
+///  Command line arguments for the k-mer counting program.
+///
+///  Options:
+///  -f, --file <file>      (required)  The FASTA file path, optionally gzip compressed, to count k-mers in
+///  -k, --k <k>            (required)  The length of the k-mers to count, between 1 and 32
+///  -C, --canonical                    A flag that counts each k-mer together with its reverse complement
+///  -s, --spectrum                     A flag that outputs the k-mer spectrum instead of the k-mer counts
+///  -F, --format <format>              The output format, 'csv' (default) or 'json'
+///  -O, --output <output>              The output file name and path, otherwise standard output
+///  -h, --help                         Print help information
+///  -V, --version                      Print version information
+fn cli() -> clap::Command {
+    clap::Command::new("kmers")
+    .version("0.1.0")
+    .author("Nathan Edwards <Nathan.W.Edwards@Outlook.com>")
+    .about("Given a FASTA file, output the counts of the k-mers in its sequences as a CSV or JSON file")
+    .arg(clap::Arg::new("file")
+        .required(true)
+        .short('f')
+        .long("file")
+        .help("The FASTA file path, optionally gzip compressed, to count k-mers in"))
+    .arg(clap::Arg::new("k")
+        .required(true)
+        .short('k')
+        .long("k")
+        .help("The length of the k-mers to count, between 1 and 32"))
+    .arg(clap::Arg::new("canonical")
+        .short('C')
+        .long("canonical")
+        .action(clap::ArgAction::SetTrue)
+        .help("A flag that counts each k-mer together with its reverse complement"))
+    .arg(clap::Arg::new("spectrum")
+        .short('s')
+        .long("spectrum")
+        .action(clap::ArgAction::SetTrue)
+        .help("A flag that outputs the k-mer spectrum instead of the k-mer counts"))
+    .arg(clap::Arg::new("format")
+        .short('F')
+        .long("format")
+        .value_parser(["csv", "json"])
+        .help("The output format, 'csv' (default) or 'json'"))
+    .arg(clap::Arg::new("output")
+        .short('O')
+        .long("output")
+        .help("The output file name and path, otherwise standard output"))
+}
@@ -0,0 +1,293 @@
+//! MIT license.
+
+/// Responses are reused for a day by default.
+pub const DEFAULT_TTL: std::time::Duration = std::time::Duration::from_secs(24 * 60 * 60);
+
+/// A request as it is cached: its method, URL, the headers that choose the response, and a digest of its body.
+///
+/// # Example
+///
+/// ```
+/// use utils::cache::CacheKey;
+///
+/// let json = CacheKey::new("GET", "https://rest.ensembl.org/lookup/id/ENSG00000155542").with_header("Accept", "application/json");
+/// let html = CacheKey::new("GET", "https://rest.ensembl.org/lookup/id/ENSG00000155542");
+/// assert_ne!(json.digest(), html.digest());
+/// assert_eq!(json.digest(), json.clone().digest());
+/// assert_eq!(json.digest().len(), 32);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CacheKey {
+    method: String,
+    url: String,
+    headers: std::collections::BTreeMap<String, String>,
+    body_digest: Option<String>,
+}
+
+impl CacheKey {
+    pub fn new(method: &str, url: &str) -> CacheKey {
+        CacheKey {
+            method: method.to_ascii_uppercase(),
+            url: String::from(url),
+            headers: std::collections::BTreeMap::new(),
+            body_digest: None,
+        }
+    }
+
+    /// Key a header of the request, such as `Accept`. Header names are not case sensitive.
+    pub fn with_header(mut self, name: &str, value: &str) -> CacheKey {
+        self.headers.insert(name.to_ascii_lowercase(), String::from(value));
+        self
+    }
+
+    /// Key the body of the request, such as the identifiers posted to EnsEMBL.
+    pub fn with_body(mut self, body: &[u8]) -> CacheKey {
+        self.body_digest = Some(format!("{:032x}", fnv1a(body)));
+        self
+    }
+
+    pub fn get_url(&self) -> &str {
+        &self.url
+    }
+
+    /// The key as text, which is stored with a cached response to tell keys of the same digest apart.
+    fn canonical(&self) -> String {
+        let headers: Vec<String> = self.headers.iter().map(|(name, value)| format!("{name}: {value}")).collect();
+        format!("{} {}\n{}\n{}", self.method, self.url, headers.join("\n"), self.body_digest.as_deref().unwrap_or(""))
+    }
+
+    /// The address of the request's cached response: a 128-bit FNV-1a hash of the key, as hexadecimal.
+    pub fn digest(&self) -> String {
+        format!("{:032x}", fnv1a(self.canonical().as_bytes()))
+    }
+}
+
+/// The 128-bit FNV-1a hash of bytes, which is the same on every platform and release.
+fn fnv1a(bytes: &[u8]) -> u128 {
+    const OFFSET_BASIS: u128 = 0x6c62272e07bb014262b821756295c58d;
+    const PRIME: u128 = 0x0000000001000000000000000000013b;
+    bytes.iter().fold(OFFSET_BASIS, |hash, byte| (hash ^ u128::from(*byte)).wrapping_mul(PRIME))
+}
+
+/// The description of a cached response, stored beside its body.
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+struct CacheEntry {
+    key: String,
+    /// Seconds since the Unix epoch.
+    stored: u64,
+}
+
+/// The number of responses a cache has served, missed and stored.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct CacheStatistics {
+    pub hits: u64,
+    /// Requests without a cached response, including those whose cached response expired.
+    pub misses: u64,
+    pub expired: u64,
+    pub stores: u64,
+}
+
+impl CacheStatistics {
+    /// The fraction of requests served from the cache, or zero if none were made.
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            requests => self.hits as f64 / requests as f64,
+        }
+    }
+}
+
+/// A request made offline without a cached response.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NotCachedError {
+    url: String,
+}
+
+impl NotCachedError {
+    pub fn new(url: &str) -> NotCachedError {
+        NotCachedError { url: String::from(url) }
+    }
+
+    pub fn get_url(&self) -> &str {
+        &self.url
+    }
+}
+
+impl std::fmt::Display for NotCachedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} is not cached, and requests are not made offline", self.url)
+    }
+}
+
+impl std::error::Error for NotCachedError {}
+
+/// A cache of successful HTTP responses in a directory, addressed by the digest of their request (see `CacheKey`).
+///
+/// Responses are reused until they are older than the cache's time to live. An offline cache serves every response
+/// it holds however old, and a request without a cached response is a `NotCachedError` rather than a request made.
+///
+/// # Example
+///
+/// ```
+/// use utils::cache::{CacheKey, HttpCache};
+///
+/// let directory = tempfile::tempdir().unwrap();
+/// let cache: HttpCache = HttpCache::new(directory.path());
+/// let key: CacheKey = CacheKey::new("GET", "https://rest.ensembl.org/info/ping");
+///
+/// assert_eq!(cache.get(&key), None);
+/// cache.put(&key, b"{\"ping\":1}").unwrap();
+/// assert_eq!(cache.get(&key), Some(b"{\"ping\":1}".to_vec()));
+///
+/// // Expired responses are not served online, but are served offline.
+/// let cache: HttpCache = HttpCache::new(directory.path()).with_ttl(std::time::Duration::ZERO);
+/// assert_eq!(cache.get(&key), None);
+/// let cache: HttpCache = cache.with_offline(true);
+/// assert_eq!(cache.get(&key), Some(b"{\"ping\":1}".to_vec()));
+/// assert_eq!(cache.statistics().hits, 1);
+/// ```
+#[derive(Debug)]
+pub struct HttpCache {
+    directory: std::path::PathBuf,
+    ttl: std::time::Duration,
+    offline: bool,
+    hits: std::sync::atomic::AtomicU64,
+    misses: std::sync::atomic::AtomicU64,
+    expired: std::sync::atomic::AtomicU64,
+    stores: std::sync::atomic::AtomicU64,
+}
+
+impl HttpCache {
+    /// An online cache in a directory, created once a response is stored, reusing responses for `DEFAULT_TTL`.
+    pub fn new(directory: &std::path::Path) -> HttpCache {
+        HttpCache {
+            directory: directory.to_path_buf(),
+            ttl: DEFAULT_TTL,
+            offline: false,
+            hits: std::sync::atomic::AtomicU64::new(0),
+            misses: std::sync::atomic::AtomicU64::new(0),
+            expired: std::sync::atomic::AtomicU64::new(0),
+            stores: std::sync::atomic::AtomicU64::new(0),
+        }
+    }
+
+    /// Reuse responses for a time to live other than `DEFAULT_TTL`.
+    pub fn with_ttl(mut self, ttl: std::time::Duration) -> HttpCache {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Serve only cached responses, however old, without making requests.
+    pub fn with_offline(mut self, offline: bool) -> HttpCache {
+        self.offline = offline;
+        self
+    }
+
+    pub fn get_directory(&self) -> &std::path::Path {
+        &self.directory
+    }
+
+    pub fn get_ttl(&self) -> std::time::Duration {
+        self.ttl
+    }
+
+    pub fn is_offline(&self) -> bool {
+        self.offline
+    }
+
+    /// The cached response body of a request, if it is cached and has not expired, or the cache is offline.
+    pub fn get(&self, key: &CacheKey) -> Option<Vec<u8>> {
+        use std::sync::atomic::Ordering;
+
+        let (entry_path, body_path) = self.paths(key);
+        let entry: Option<CacheEntry> = std::fs::read(&entry_path)
+            .ok()
+            .and_then(|entry| serde_json::from_slice::<CacheEntry>(&entry).ok())
+            .filter(|entry| entry.key == key.canonical());
+        let Some(entry) = entry else {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+            return None;
+        };
+
+        let age: std::time::Duration = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH + std::time::Duration::from_secs(entry.stored))
+            .unwrap_or_default();
+        if !self.offline && age >= self.ttl {
+            self.expired.fetch_add(1, Ordering::Relaxed);
+            self.misses.fetch_add(1, Ordering::Relaxed);
+            return None;
+        }
+
+        match std::fs::read(&body_path) {
+            Ok(body) => {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                Some(body)
+            }
+            Err(_) => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    /// Store the response body of a request, replacing any response cached for it.
+    pub fn put(&self, key: &CacheKey, body: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+        let (entry_path, body_path) = self.paths(key);
+        if let Some(directory) = entry_path.parent() {
+            std::fs::create_dir_all(directory)?;
+        }
+        let entry: CacheEntry = CacheEntry {
+            key: key.canonical(),
+            stored: std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)?.as_secs(),
+        };
+        // The entry is written after the body, so that a response is only found once it is complete.
+        write_file(&body_path, body)?;
+        write_file(&entry_path, serde_json::to_string(&entry)?.as_bytes())?;
+        self.stores.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        Ok(())
+    }
+
+    pub fn statistics(&self) -> CacheStatistics {
+        use std::sync::atomic::Ordering;
+        CacheStatistics {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            expired: self.expired.load(Ordering::Relaxed),
+            stores: self.stores.load(Ordering::Relaxed),
+        }
+    }
+
+    /// The paths of a request's entry and body, in a subdirectory of the first two characters of its digest.
+    fn paths(&self, key: &CacheKey) -> (std::path::PathBuf, std::path::PathBuf) {
+        let digest: String = key.digest();
+        let directory: std::path::PathBuf = self.directory.join(&digest[..2]);
+        (directory.join(format!("{digest}.json")), directory.join(format!("{digest}.body")))
+    }
+}
+
+/// The number of temporary files written by this process, which names each of them apart from the others.
+static TEMPORARY_FILES: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+/// Write a file through a temporary file renamed into place, so that readers never see a partly written file.
+///
+/// The temporary file is named after the process and a counter, so that concurrent writes of the same file,
+/// by this process or another, do not write to the same temporary file.
+fn write_file(path: &std::path::Path, contents: &[u8]) -> std::io::Result<()> {
+    let number: u64 = TEMPORARY_FILES.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    let temporary: std::path::PathBuf = path.with_extension(format!("{}.{}.tmp", std::process::id(), number));
+    std::fs::write(&temporary, contents)?;
+    std::fs::rename(&temporary, path)
+}
+
+/// The cache `web` requests are served from, if one is configured.
+static GLOBAL: std::sync::OnceLock<HttpCache> = std::sync::OnceLock::new();
+
+/// Serve every request made by `web` from a cache. A cache can be configured once; the cache is returned if one already is.
+pub fn configure(cache: HttpCache) -> Result<(), HttpCache> {
+    GLOBAL.set(cache)
+}
+
+/// The cache configured with `configure`, if any.
+pub fn global() -> Option<&'static HttpCache> {
+    GLOBAL.get()
+}
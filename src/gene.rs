@@ -2,7 +2,21 @@
 
 use serde::ser::SerializeStruct;
 
-#[derive(Clone)]
+/// An EnsEMBL gene and its DNA.
+///
+/// Coordinates are 1-based and inclusive, as in EnsEMBL. Fields EnsEMBL did not return are empty, or `None`.
+///
+/// # Example
+///
+/// ```
+/// let json: &str = r#"{"id": "ENSG00000162994", "display_name": "CLHC1", "start": 55172547, "end": 55232563, "strand": -1}"#;
+/// let gene: utils::gene::Gene = serde_json::from_str(json).unwrap();
+/// assert_eq!(gene.get_end().unwrap() - gene.get_start().unwrap() + 1, 60017);
+/// assert_eq!(gene.get_strand(), Some(utils::seq::Strand::Reverse));
+/// assert_eq!(gene.get_version(), None);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize)]
+#[serde(default)]
 pub struct Gene {
     assembly_name: String,
     biotype: String,
@@ -10,58 +24,61 @@ pub struct Gene {
     db_type: String,
     description: String,
     display_name: String,
-    dna: String,
-    end: String,
+    dna: crate::seq::nucleotide::DnaSequence,
+    end: Option<u64>,
     id: String,
     logic_name: String,
     object_type: String,
     seq_region_name: String,
     source: String,
     species: String,
-    start: String,
-    strand: String,
-    version: String,
+    start: Option<u64>,
+    strand: Option<crate::seq::Strand>,
+    #[serde(rename = "Transcript")]
+    transcripts: Vec<crate::transcript::Transcript>,
+    version: Option<u64>,
+    xrefs: Vec<crate::xref::ExternalReference>,
 }
 
 impl Gene {
-    pub fn new(
-        assembly_name: &str,
-        biotype: &str,
-        canonical_transcript: &str,
-        db_type: &str,
-        description: &str,
-        display_name: &str,
-        dna: &str,
-        end: &str,
-        id: &str,
-        logic_name: &str,
-        object_type: &str,
-        seq_region_name: &str,
-        source: &str,
-        species: &str,
-        start: &str,
-        strand: &str,
-        version: &str,
-    ) -> Gene {
-        Gene {
-            assembly_name: String::from(assembly_name),
-            biotype: String::from(biotype),
-            canonical_transcript: String::from(canonical_transcript),
-            db_type: String::from(db_type),
-            description: String::from(description),
-            display_name: String::from(display_name),
-            dna: String::from(dna),
-            end: String::from(end),
-            id: String::from(id),
-            logic_name: String::from(logic_name),
-            object_type: String::from(object_type),
-            seq_region_name: String::from(seq_region_name),
-            source: String::from(source),
-            species: String::from(species),
-            start: String::from(start),
-            strand: String::from(strand),
-            version: String::from(version)
+    /// Return a builder of a gene, with every field empty until it is set.
+    pub fn builder() -> GeneBuilder {
+        GeneBuilder::default()
+    }
+
+    /// Read genes from a CSV file written by the `ensembl_search` program.
+    ///
+    /// Columns other than the gene's fields, such as sequence statistics, are ignored, and missing columns are left empty.
+    /// A `dna` column that is not IUPAC nucleotide codes is an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::io::Write;
+    ///
+    /// let csv_contents: &str = "assembly_name,biotype,canonical_transcript,db_type,description,display_name,dna,end,id,logic_name,object_type,seq_region_name,source,species,start,strand,version,gc_content
+    /// GRCh38,protein_coding,ENST00000401408.6,core,clathrin heavy chain linker domain containing 1 [Source:HGNC Symbol;Acc:HGNC:26453],CLHC1,TTTTTATGTcag,55172558,ENSG00000162994,ensembl_havana_gene_homo_sapiens,Gene,2,ensembl_havana,homo_sapiens,55172547,-1,16,0.25
+    /// GRCh38,protein_coding,,core,,,,,ENSG00000155542,,Gene,5,,homo_sapiens,,,,
+    /// ";
+    /// let mut csv_file: tempfile::NamedTempFile = tempfile::NamedTempFile::new().unwrap();
+    /// csv_file.write_all(csv_contents.as_bytes()).unwrap();
+    /// let genes: Vec<utils::gene::Gene> = utils::gene::Gene::read_csv(csv_file.path()).unwrap();
+    /// assert_eq!(genes.len(), 2);
+    /// assert_eq!(genes[0].get_strand(), Some(utils::seq::Strand::Reverse));
+    /// assert_eq!(genes[0].get_oriented_dna(), "ctgACATAAAAA");
+    /// assert_eq!(genes[1].get_start(), None);
+    ///
+    /// csv_file.as_file().set_len(0).unwrap();
+    /// csv_file.write_all(b"id,dna\nENSG00000162994,ACGU\n").unwrap();
+    /// assert!(utils::gene::Gene::read_csv(csv_file.path()).is_err());
+    /// ```
+    pub fn read_csv(filename: &std::path::Path) -> Result<Vec<Gene>, Box<dyn std::error::Error>> {
+        let mut reader: csv::Reader<std::fs::File> = csv::Reader::from_path(filename)?;
+        let mut genes: Vec<Gene> = Vec::new();
+        for row in reader.deserialize::<Gene>() {
+            genes.push(row?);
         }
+        Ok(genes)
     }
 
     pub fn get_assembly_name(&self) -> &str {
@@ -88,12 +105,48 @@ impl Gene {
         return &self.display_name;
     }
 
-    pub fn get_dna(&self) -> &str {
+    /// The gene's DNA, on the forward strand from the gene's start to its end.
+    pub fn get_dna(&self) -> &crate::seq::nucleotide::DnaSequence {
         return &self.dna;
     }
 
-    pub fn get_end(&self) -> &str {
-        return &self.end;
+    /// Return the gene's DNA read in the direction of transcription: the reverse complement of the DNA
+    /// when the gene is on the reverse strand, otherwise the DNA as stored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let gene: utils::gene::Gene = utils::gene::Gene::builder()
+    ///     .assembly_name("GRCh38")
+    ///     .biotype("protein_coding")
+    ///     .canonical_transcript("ENST00000401408.6")
+    ///     .db_type("core")
+    ///     .description("clathrin heavy chain linker domain containing 1 [Source:HGNC Symbol;Acc:HGNC:26453]")
+    ///     .display_name("CLHC1")
+    ///     .dna("TTTTTATGTcag".parse().unwrap())
+    ///     .end(55172558)
+    ///     .id("ENSG00000162994")
+    ///     .logic_name("ensembl_havana_gene_homo_sapiens")
+    ///     .object_type("Gene")
+    ///     .seq_region_name("2")
+    ///     .source("ensembl_havana")
+    ///     .species("homo_sapiens")
+    ///     .start(55172547)
+    ///     .strand(utils::seq::Strand::Reverse)
+    ///     .version(16)
+    ///     .build();
+    /// assert_eq!(gene.get_oriented_dna(), "ctgACATAAAAA");
+    /// ```
+    pub fn get_oriented_dna(&self) -> String {
+        match self.strand {
+            Some(crate::seq::Strand::Reverse) => self.dna.reverse_complement().to_string(),
+            _ => self.dna.to_string(),
+        }
+    }
+
+    /// The 1-based, inclusive end of the gene on its chromosome.
+    pub fn get_end(&self) -> Option<u64> {
+        self.end
     }
 
     pub fn get_id(&self) -> &str {
@@ -120,16 +173,138 @@ impl Gene {
         return &self.species;
     }
 
-    pub fn get_start(&self) -> &str {
-        return &self.start;
+    /// The 1-based, inclusive start of the gene on its chromosome.
+    pub fn get_start(&self) -> Option<u64> {
+        self.start
+    }
+
+    pub fn get_strand(&self) -> Option<crate::seq::Strand> {
+        self.strand
+    }
+
+    /// The gene's transcripts, when the gene was looked up with its transcripts expanded.
+    pub fn get_transcripts(&self) -> &[crate::transcript::Transcript] {
+        &self.transcripts
+    }
+
+    /// Return the canonical transcript's model, flagged canonical by EnsEMBL or named by the gene's canonical transcript.
+    pub fn find_canonical_transcript(&self) -> Option<&crate::transcript::Transcript> {
+        self.transcripts.iter().find(|transcript| {
+            transcript.is_canonical()
+                || transcript.get_versioned_id() == self.canonical_transcript
+                || transcript.get_id() == self.canonical_transcript
+        })
+    }
+
+    /// Assemble the spliced cDNA of one of the gene's transcripts from the gene's DNA.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let json: &str = r#"{"id": "ENSG00000000001", "start": 101, "end": 120, "strand": 1, "dna": "ATGAAAcccGGGTAAggTTT",
+    ///                      "Transcript": [{"id": "ENST00000000001", "start": 101, "end": 120, "strand": 1, "is_canonical": 1,
+    ///                                      "Exon": [{"start": 101, "end": 106}, {"start": 110, "end": 120}],
+    ///                                      "Translation": {"start": 101, "end": 115}}]}"#;
+    /// let gene: utils::gene::Gene = serde_json::from_str(json).unwrap();
+    /// let transcript: &utils::transcript::Transcript = gene.find_canonical_transcript().unwrap();
+    /// assert_eq!(gene.transcript_cdna(transcript).unwrap(), "ATGAAAGGGTAAggTTT");
+    /// assert_eq!(gene.transcript_cds(transcript).unwrap(), "ATGAAAGGGTAA");
+    /// ```
+    pub fn transcript_cdna(&self, transcript: &crate::transcript::Transcript) -> Result<String, Box<dyn std::error::Error>> {
+        transcript.get_cdna(&self.dna.to_string(), self.get_chromosome_start()? as u64)
+    }
+
+    /// Assemble the coding sequence of one of the gene's transcripts from the gene's DNA.
+    pub fn transcript_cds(&self, transcript: &crate::transcript::Transcript) -> Result<String, Box<dyn std::error::Error>> {
+        transcript.get_cds(&self.dna.to_string(), self.get_chromosome_start()? as u64)
+    }
+
+    pub fn get_version(&self) -> Option<u64> {
+        self.version
+    }
+
+    /// The gene's cross-references to external databases, when they have been retrieved.
+    pub fn get_xrefs(&self) -> &[crate::xref::ExternalReference] {
+        &self.xrefs
+    }
+
+    /// The identifiers of the gene in an external database, such as its NCBI Gene identifiers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use utils::xref::{ExternalDatabase, ExternalReference};
+    ///
+    /// let gene: utils::gene::Gene = utils::gene::Gene::builder()
+    ///     .id("ENSG00000000003")
+    ///     .build()
+    ///     .with_xrefs(vec![
+    ///         ExternalReference::new(ExternalDatabase::Hgnc, "HGNC:11858", "TSPAN6"),
+    ///         ExternalReference::new(ExternalDatabase::NcbiGene, "7105", "TSPAN6"),
+    ///     ]);
+    /// assert_eq!(gene.get_xref_ids(&ExternalDatabase::NcbiGene), ["7105"]);
+    /// assert!(gene.get_xref_ids(&ExternalDatabase::Omim).is_empty());
+    /// ```
+    pub fn get_xref_ids(&self, database: &crate::xref::ExternalDatabase) -> Vec<&str> {
+        self.xrefs
+            .iter()
+            .filter(|xref| xref.get_database() == database)
+            .map(|xref| xref.get_primary_id())
+            .collect()
+    }
+
+    /// Return the gene with its DNA, on the forward strand from the gene's start to its end.
+    pub fn with_dna(mut self, dna: crate::seq::nucleotide::DnaSequence) -> Gene {
+        self.dna = dna;
+        self
+    }
+
+    /// Return the gene with its cross-references to external databases.
+    pub fn with_xrefs(mut self, xrefs: Vec<crate::xref::ExternalReference>) -> Gene {
+        self.xrefs = xrefs;
+        self
+    }
+
+    /// Serialize the gene's fields as fields of an enclosing struct, such as a CSV row with additional columns.
+    pub fn serialize_fields<S>(&self, serialized_gene: &mut S) -> Result<(), S::Error>
+    where
+        S: serde::ser::SerializeStruct,
+    {
+        serialized_gene.serialize_field("assembly_name", &self.assembly_name)?;
+        serialized_gene.serialize_field("biotype", &self.biotype)?;
+        serialized_gene.serialize_field("canonical_transcript", &self.canonical_transcript)?;
+        serialized_gene.serialize_field("db_type", &self.db_type)?;
+        serialized_gene.serialize_field("description", &self.description)?;
+        serialized_gene.serialize_field("display_name", &self.display_name)?;
+        serialized_gene.serialize_field("dna", &self.dna)?;
+        serialized_gene.serialize_field("end", &self.end)?;
+        serialized_gene.serialize_field("id", &self.id)?;
+        serialized_gene.serialize_field("logic_name", &self.logic_name)?;
+        serialized_gene.serialize_field("object_type", &self.object_type)?;
+        serialized_gene.serialize_field("seq_region_name", &self.seq_region_name)?;
+        serialized_gene.serialize_field("source", &self.source)?;
+        serialized_gene.serialize_field("species", &self.species)?;
+        serialized_gene.serialize_field("start", &self.start)?;
+        serialized_gene.serialize_field("strand", &self.strand)?;
+        serialized_gene.serialize_field("version", &self.version)?;
+        Ok(())
     }
 
-    pub fn get_strand(&self) -> &str {
-        return &self.strand;
+    /// The gene's start as a chromosome position, or an error if the gene's start is unknown.
+    fn get_chromosome_start(&self) -> Result<usize, Box<dyn std::error::Error>> {
+        match self.start {
+            Some(start) => Ok(start as usize),
+            None => {
+                eprintln!("{{\"id\": \"{}\", \"error\": \"The gene's start is unknown.\"}}", self.id);
+                Err(Box::new(std::io::Error::new(std::io::ErrorKind::InvalidData, "the gene's start is unknown")))
+            }
+        }
     }
 
-    pub fn get_version(&self) -> &str {
-        return &self.version;
+    /// Compute composition metrics of the gene's DNA: GC content and skew, the CpG observed/expected ratio,
+    /// N content, the soft-masked fraction (the interspersed repeat regions' share of the DNA) and the longest homopolymer.
+    pub fn statistics(&self) -> crate::seq::statistics::SequenceStatistics {
+        crate::seq::statistics::SequenceStatistics::new(&self.dna.to_string())
     }
 
     /// Return a vector of repeat regions present in the gene.
@@ -138,25 +313,25 @@ impl Gene {
     /// 
     /// ```
     /// let expected: Vec<&str> = vec!["gggcgggcccgggtgggcagggactgaggtgggcgggccgggtgggcggg","cagcttggctgttgggtgtagtacgggaaagagctgaacttcgtttctgcctctggctctcccacttactggctttgggaccttgaacaagttccctaacaattttgctttccttgtctgcaaaaatggaataatgtaaggaccccacag"];
-    /// let gene: utils::gene::Gene = utils::gene::Gene::new(
-    ///                     "GRCh38",
-    ///                     "protein_coding",
-    ///                     "ENST00000285947.5",
-    ///                     "core",
-    ///                     "SET domain containing 9 [Source:HGNC Symbol;Acc:HGNC:28508]",
-    ///                     "SETD9",
-    ///                     "GACAGCCGTGACAGAGAAACTAGGCGGTCCGCTCCTGGGACGCGGTCTCGGGGGCGGGTTCGCCCCTGAGGCCCGGTGACCCTTCGCCCGCAACCAGGGCTAGCGCTTGTGTGCGCCCACGGAAGCACCCGAGCGACCGGAGAAAGAAAAAGTGGTCAAGGGGACCTCCTTCCAGCTCTACGCGCCTCTCATTCAGAGAGGTGAGGTCAGGAAAGGGGAAGGACGAAGCCCCAGAGCAACAGAAGTCAGGCGGTGCCAGGAACACTGAGAGCGGAGCCAGGGGAAGGCGGCCGAGCGCGGCCCCCTCTCCTCCCGGGCCGGGGCGGGCCCGAGGCCTCGATCCGCCTTCCCCGCGCCGTCCTGGTCACGGCCCCGCGGGGCAGCCATGCCTGGCCGTCTGCTGCGGGGCCTGTGGCAGCGATGGCGCCGTTACAAGTACCGCTTCGTTCCCTGGATCGCACTGAACCTAAGCCACAACCCGAGGTGAGAGGGCGGGACGGCAGAACGAGGGGCACCTGCCTTCGGTTCCCAGACGCCACCACGGCGGCGGGACGCAAAGCGGAGAGCCTGAGGCTGACTGCCGGCCTGAGATgggcgggcccgggtgggcagggactgaggtgggcgggccgggtgggcgggGACTGAGGCCTCGGAGGGGTTTAAGGAACGCGGGCCAGAGGCGGGCGGGGCCGAGGTTGGTGGAGTCCGAGGCCCGCTGGAAGCCTGAAGTGGGCGGTGGCTTCAGGTGGGCGGGGCCTATGGCCTCTTTCCCAGTGTCCGCTGCGCTGCCGGGCCCGCGAGGCCGAGCTCGCCAGCCGGATGTGTCGCCTGTTCTTCCCTGTTGCGTTCGGCACTGACTGGGGAGCTTGTATCTGGGCAAAAATGGAAACTTTTTAAAAAGACTGCCCTTACCGCGTGAGAGTGCGTGGCTTTTTCTCCACCAGGGGTTAATTAGGTGCTTGAACTTCCTCAGAAAAGCCAAGCCAGGGTTTATTTTCATAGTTCATACAAGTACTTCACGTGGTTAAAGAAGCCCATCCCGGCTGTGAGAATAGCGTGCAGTAGCTCGCGCGTTAAGAACGGGCAGAACGCCACTCAAAAGCACGTCGGGATGGTGGGTTTCGGATTGGGGTGAGTCCCGCCGGCGTTATTAAGGGCGCACCAGTGATCAGCTCAACACCGTGCTCACCAAAGAGGCCGACCGGGCCTCCCTGAAGTCAGTGTCCGACAAATAGGGAAACAAAGCGGTTTCCGGAAGGTTGCATGCTAGGGAAACAGGAGATCAGTTGTATCATGACTCGGAACCGTGAGGCGGGAATTACAACCTTTTTGTTCAAGTAAAAATAATGCAGTTAGTACAAAAAGATAATGATTATGGAAATAAGAGGTGCAGTCATTGAAGACGTTAGTCGTCCTGGAGGGAGTGTCACAAAGTATACGATGTTGAATTTGTCTTTGAAACTACAAGGCCATTTTTAAAAAGCCATTGAATGTGAGGTCATTCATTCTCATTGTGATGACATTcagcttggctgttgggtgtagtacgggaaagagctgaacttcgtttctgcctctggctctcccacttactggctttgggaccttgaacaagttccctaacaattttgctttccttgtctgcaaaaatggaataatgtaaggaccccacagCATGTGGTATAGAATGGGCCTTTCATAAGTACTGACTACTTGGGTGGG",
-    ///                     "56925532",
-    ///                     "ENSG00000155542",
-    ///                     "ensembl_havana_gene_homo_sapiens",
-    ///                     "Gene",
-    ///                     "5",
-    ///                     "ensembl_havana",
-    ///                     "homo_sapiens",
-    ///                     "56909260",
-    ///                     "1",
-    ///                     "12"
-    /// );
+    /// let gene: utils::gene::Gene = utils::gene::Gene::builder()
+    ///     .assembly_name("GRCh38")
+    ///     .biotype("protein_coding")
+    ///     .canonical_transcript("ENST00000285947.5")
+    ///     .db_type("core")
+    ///     .description("SET domain containing 9 [Source:HGNC Symbol;Acc:HGNC:28508]")
+    ///     .display_name("SETD9")
+    ///     .dna("GACAGCCGTGACAGAGAAACTAGGCGGTCCGCTCCTGGGACGCGGTCTCGGGGGCGGGTTCGCCCCTGAGGCCCGGTGACCCTTCGCCCGCAACCAGGGCTAGCGCTTGTGTGCGCCCACGGAAGCACCCGAGCGACCGGAGAAAGAAAAAGTGGTCAAGGGGACCTCCTTCCAGCTCTACGCGCCTCTCATTCAGAGAGGTGAGGTCAGGAAAGGGGAAGGACGAAGCCCCAGAGCAACAGAAGTCAGGCGGTGCCAGGAACACTGAGAGCGGAGCCAGGGGAAGGCGGCCGAGCGCGGCCCCCTCTCCTCCCGGGCCGGGGCGGGCCCGAGGCCTCGATCCGCCTTCCCCGCGCCGTCCTGGTCACGGCCCCGCGGGGCAGCCATGCCTGGCCGTCTGCTGCGGGGCCTGTGGCAGCGATGGCGCCGTTACAAGTACCGCTTCGTTCCCTGGATCGCACTGAACCTAAGCCACAACCCGAGGTGAGAGGGCGGGACGGCAGAACGAGGGGCACCTGCCTTCGGTTCCCAGACGCCACCACGGCGGCGGGACGCAAAGCGGAGAGCCTGAGGCTGACTGCCGGCCTGAGATgggcgggcccgggtgggcagggactgaggtgggcgggccgggtgggcgggGACTGAGGCCTCGGAGGGGTTTAAGGAACGCGGGCCAGAGGCGGGCGGGGCCGAGGTTGGTGGAGTCCGAGGCCCGCTGGAAGCCTGAAGTGGGCGGTGGCTTCAGGTGGGCGGGGCCTATGGCCTCTTTCCCAGTGTCCGCTGCGCTGCCGGGCCCGCGAGGCCGAGCTCGCCAGCCGGATGTGTCGCCTGTTCTTCCCTGTTGCGTTCGGCACTGACTGGGGAGCTTGTATCTGGGCAAAAATGGAAACTTTTTAAAAAGACTGCCCTTACCGCGTGAGAGTGCGTGGCTTTTTCTCCACCAGGGGTTAATTAGGTGCTTGAACTTCCTCAGAAAAGCCAAGCCAGGGTTTATTTTCATAGTTCATACAAGTACTTCACGTGGTTAAAGAAGCCCATCCCGGCTGTGAGAATAGCGTGCAGTAGCTCGCGCGTTAAGAACGGGCAGAACGCCACTCAAAAGCACGTCGGGATGGTGGGTTTCGGATTGGGGTGAGTCCCGCCGGCGTTATTAAGGGCGCACCAGTGATCAGCTCAACACCGTGCTCACCAAAGAGGCCGACCGGGCCTCCCTGAAGTCAGTGTCCGACAAATAGGGAAACAAAGCGGTTTCCGGAAGGTTGCATGCTAGGGAAACAGGAGATCAGTTGTATCATGACTCGGAACCGTGAGGCGGGAATTACAACCTTTTTGTTCAAGTAAAAATAATGCAGTTAGTACAAAAAGATAATGATTATGGAAATAAGAGGTGCAGTCATTGAAGACGTTAGTCGTCCTGGAGGGAGTGTCACAAAGTATACGATGTTGAATTTGTCTTTGAAACTACAAGGCCATTTTTAAAAAGCCATTGAATGTGAGGTCATTCATTCTCATTGTGATGACATTcagcttggctgttgggtgtagtacgggaaagagctgaacttcgtttctgcctctggctctcccacttactggctttgggaccttgaacaagttccctaacaattttgctttccttgtctgcaaaaatggaataatgtaaggaccccacagCATGTGGTATAGAATGGGCCTTTCATAAGTACTGACTACTTGGGTGGG".parse().unwrap())
+    ///     .end(56925532)
+    ///     .id("ENSG00000155542")
+    ///     .logic_name("ensembl_havana_gene_homo_sapiens")
+    ///     .object_type("Gene")
+    ///     .seq_region_name("5")
+    ///     .source("ensembl_havana")
+    ///     .species("homo_sapiens")
+    ///     .start(56909260)
+    ///     .strand(utils::seq::Strand::Forward)
+    ///     .version(12)
+    ///     .build();
     /// let result: Vec<String> = gene.interspersed_repeat_regions().unwrap();
     /// assert_eq!(result, expected);
     /// ```
@@ -170,36 +345,191 @@ impl Gene {
         };
 
         let mut repeats: Vec<String> = Vec::new();
-        for matched_expression in regular_expression.find_iter(&self.dna) {
+        let dna: String = self.dna.to_string();
+        for matched_expression in regular_expression.find_iter(&dna) {
             repeats.push(matched_expression.as_str().to_string());
         };
 
         Ok(repeats)
     }
 
+    /// Return the tandem repeats in the gene's DNA with a period in `periods`, spanning at least `minimum_length` bases,
+    /// whose purity is at least `minimum_purity`.
+    ///
+    /// Tandem repeats are positioned on the gene's chromosome: like the gene's `start` and `end`,
+    /// each tandem repeat's start and end are 1-based and inclusive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let gene: utils::gene::Gene = utils::gene::Gene::builder()
+    ///     .assembly_name("GRCh38")
+    ///     .biotype("protein_coding")
+    ///     .canonical_transcript("ENST00000285947.5")
+    ///     .db_type("core")
+    ///     .description("SET domain containing 9 [Source:HGNC Symbol;Acc:HGNC:28508]")
+    ///     .display_name("SETD9")
+    ///     .dna("GACAGCCGTGACAGAGAAACTAGGCGGTCCGcacacacacacacacacaCTCCTGGGACGCGG".parse().unwrap())
+    ///     .end(56909322)
+    ///     .id("ENSG00000155542")
+    ///     .logic_name("ensembl_havana_gene_homo_sapiens")
+    ///     .object_type("Gene")
+    ///     .seq_region_name("5")
+    ///     .source("ensembl_havana")
+    ///     .species("homo_sapiens")
+    ///     .start(56909260)
+    ///     .strand(utils::seq::Strand::Forward)
+    ///     .version(12)
+    ///     .build();
+    /// let result = gene.tandem_repeats(utils::seq::tandem::MICROSATELLITE_PERIODS, 10, 0.9).unwrap();
+    /// assert_eq!(result.len(), 1);
+    /// assert_eq!(result[0].get_motif(), "CA");
+    /// assert_eq!(result[0].get_sequence(), utils::seq::Sequence::new(56909291, 56909309));
+    /// ```
+    pub fn tandem_repeats(
+        &self,
+        periods: std::ops::RangeInclusive<usize>,
+        minimum_length: usize,
+        minimum_purity: f64,
+    ) -> Result<Vec<crate::seq::tandem::TandemRepeat>, Box<dyn std::error::Error>> {
+        let start: usize = self.get_chromosome_start()?;
+
+        Ok(crate::seq::tandem::find_tandem_repeats(&self.dna.to_string(), periods, minimum_length, minimum_purity)
+            .iter()
+            .map(|repeat| {
+                let sequence: crate::seq::Sequence = repeat.get_sequence();
+                repeat.with_sequence(crate::seq::Sequence::new(
+                    start + sequence.get_start(),
+                    start + sequence.get_end() - 1,
+                ))
+            })
+            .collect())
+    }
+
+    /// Count the k-mers of length `k` in the gene's DNA, combining each k-mer with its reverse complement when `canonical` is true.
+    pub fn kmer_counts(&self, k: usize, canonical: bool) -> Result<crate::seq::kmer::KmerCounts, Box<dyn std::error::Error>> {
+        let mut counts: crate::seq::kmer::KmerCounts = crate::seq::kmer::KmerCounts::new(k, canonical)?;
+        counts.add(&self.dna);
+        Ok(counts)
+    }
+
+    /// Translate the three reading frames of each strand of the gene's DNA with a genetic code.
+    pub fn six_frame_translation(&self, code: &crate::seq::translation::GeneticCode) -> Vec<crate::seq::translation::FrameTranslation> {
+        crate::seq::translation::six_frame_translation(&self.dna, code)
+    }
+
+    /// Return the open reading frames on both strands of the gene's DNA encoding at least `minimum_length` amino acids,
+    /// beginning with one of `start_codons`.
+    ///
+    /// Like the gene's `start` and `end`, each open reading frame's start and end are 1-based and inclusive on the chromosome.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let gene: utils::gene::Gene = utils::gene::Gene::builder()
+    ///     .assembly_name("GRCh38")
+    ///     .biotype("protein_coding")
+    ///     .canonical_transcript("ENST00000285947.5")
+    ///     .db_type("core")
+    ///     .description("SET domain containing 9 [Source:HGNC Symbol;Acc:HGNC:28508]")
+    ///     .display_name("SETD9")
+    ///     .dna("GCCATGCCTGGCCGTCTGCTGCGGGGCCTGTGGCAGCGATGGCGCCGTTACAAGTACCGCTTCGTTCCCTGGATCGCACTGAACTAAAGCC".parse().unwrap())
+    ///     .end(56909350)
+    ///     .id("ENSG00000155542")
+    ///     .logic_name("ensembl_havana_gene_homo_sapiens")
+    ///     .object_type("Gene")
+    ///     .seq_region_name("5")
+    ///     .source("ensembl_havana")
+    ///     .species("homo_sapiens")
+    ///     .start(56909260)
+    ///     .strand(utils::seq::Strand::Forward)
+    ///     .version(12)
+    ///     .build();
+    /// let code = utils::seq::translation::GeneticCode::standard();
+    /// let result = gene.open_reading_frames(code, 20, &["ATG"]).unwrap();
+    /// assert_eq!(result.len(), 1);
+    /// assert_eq!(result[0].get_sequence(), utils::seq::Sequence::new(56909263, 56909346));
+    /// assert_eq!(result[0].get_protein().to_string(), "MPGRLLRGLWQRWRRYKYRFVPWIALN");
+    /// ```
+    pub fn open_reading_frames<S: AsRef<str>>(
+        &self,
+        code: &crate::seq::translation::GeneticCode,
+        minimum_length: usize,
+        start_codons: &[S],
+    ) -> Result<Vec<crate::seq::translation::OpenReadingFrame>, Box<dyn std::error::Error>> {
+        let start: usize = self.get_chromosome_start()?;
+
+        Ok(crate::seq::translation::find_open_reading_frames(&self.dna, code, minimum_length, start_codons)
+            .iter()
+            .map(|frame| {
+                let sequence: crate::seq::Sequence = frame.get_sequence();
+                frame.with_sequence(crate::seq::Sequence::with_strand(
+                    start + sequence.get_start(),
+                    start + sequence.get_end() - 1,
+                    sequence.get_strand(),
+                ))
+            })
+            .collect())
+    }
+
+    /// Return the gene's DNA, read in the direction of transcription, as a FASTA record.
+    ///
+    /// The header follows EnsEMBL's FASTA exports: the EnsEMBL identifier followed by
+    /// `chromosome:<assembly>:<seq_region_name>:<start>:<end>:<strand>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let gene: utils::gene::Gene = utils::gene::Gene::builder()
+    ///     .assembly_name("GRCh38")
+    ///     .biotype("protein_coding")
+    ///     .canonical_transcript("ENST00000401408.6")
+    ///     .db_type("core")
+    ///     .description("clathrin heavy chain linker domain containing 1 [Source:HGNC Symbol;Acc:HGNC:26453]")
+    ///     .display_name("CLHC1")
+    ///     .dna("TTTTTATGTcag".parse().unwrap())
+    ///     .end(55172558)
+    ///     .id("ENSG00000162994")
+    ///     .logic_name("ensembl_havana_gene_homo_sapiens")
+    ///     .object_type("Gene")
+    ///     .seq_region_name("2")
+    ///     .source("ensembl_havana")
+    ///     .species("homo_sapiens")
+    ///     .start(55172547)
+    ///     .strand(utils::seq::Strand::Reverse)
+    ///     .version(16)
+    ///     .build();
+    /// let record: utils::seq::io::FastaRecord = gene.to_fasta_record();
+    /// assert_eq!(record.get_id(), "ENSG00000162994");
+    /// assert_eq!(record.get_description(), "chromosome:GRCh38:2:55172547:55172558:-1");
+    /// assert_eq!(record.get_sequence(), "ctgACATAAAAA");
+    /// ```
+    pub fn to_fasta_record(&self) -> crate::seq::io::FastaRecord {
+        let description: String = format!(
+            "chromosome:{}:{}:{}:{}:{}",
+            self.assembly_name,
+            self.seq_region_name,
+            self.start.map(|start| start.to_string()).unwrap_or_default(),
+            self.end.map(|end| end.to_string()).unwrap_or_default(),
+            self.strand.map(|strand| strand.to_string()).unwrap_or_default()
+        );
+        crate::seq::io::FastaRecord::new(&self.id, &description, &self.get_oriented_dna())
+    }
+
+    /// Look up a gene at the EnsEMBL REST API, with its DNA from the University of California Santa Cruz Genome Browser if `query_dna` is set.
     pub async fn lookup(
         client: Option<&awc::Client>,
         ensembl_id: &String,
-        query_dna: bool,
-        last_request_made: Option<std::time::SystemTime>,
-        number_of_requests_made: Option<u32>
+        query_dna: bool
     ) -> Result<Gene, Box<dyn std::error::Error>> {
-        let genome_browser_result = match client {
-            Some(awc_client) => {
-                crate::genome_browser::ensembl_search(awc_client,
-                    ensembl_id,
-                    query_dna,
-                    last_request_made,
-                    number_of_requests_made).await
-            },
-            None => {
-                crate::genome_browser::ensembl_search(&awc::Client::default(),
-                    ensembl_id,
-                    query_dna,
-                    last_request_made,
-                    number_of_requests_made).await
-            }
+        let client: awc::Client = client.cloned().unwrap_or_default();
+        let ensembl = crate::genome_browser::ensembl::EnsemblGenomeBrowser::new(client.clone());
+        let ucsc = crate::genome_browser::ucsc::UcscGenomeBrowser::new(client);
+        let sequence_browser: Option<&dyn crate::genome_browser::GenomeBrowser> = match query_dna {
+            true => Some(&ucsc),
+            false => None,
         };
+        let genome_browser_result = crate::genome_browser::ensembl_search(&ensembl, ensembl_id, sequence_browser).await;
 
         match genome_browser_result {
             Ok(result) => Ok(result.gene),
@@ -213,25 +543,25 @@ impl Gene {
     /// 
     /// ```
     /// let expected: Vec<&str> = vec!["GACAGCCGTGACAGAGAAACTAGGCGGTCCGCTCCTGGGACGCGGTCTCGGGGGCGGGTTCGCCCCTGAGGCCCGGTGACCCTTCGCCCGCAACCAGGGCTAGCGCTTGTGTGCGCCCACGGAAGCACCCGAGCGACCGGAGAAAGAAAAAGTGGTCAAGGGGACCTCCTTCCAGCTCTACGCGCCTCTCATTCAGAGAGGTGAGGTCAGGAAAGGGGAAGGACGAAGCCCCAGAGCAACAGAAGTCAGGCGGTGCCAGGAACACTGAGAGCGGAGCCAGGGGAAGGCGGCCGAGCGCGGCCCCCTCTCCTCCCGGGCCGGGGCGGGCCCGAGGCCTCGATCCGCCTTCCCCGCGCCGTCCTGGTCACGGCCCCGCGGGGCAGCCATGCCTGGCCGTCTGCTGCGGGGCCTGTGGCAGCGATGGCGCCGTTACAAGTACCGCTTCGTTCCCTGGATCGCACTGAACCTAAGCCACAACCCGAGGTGAGAGGGCGGGACGGCAGAACGAGGGGCACCTGCCTTCGGTTCCCAGACGCCACCACGGCGGCGGGACGCAAAGCGGAGAGCCTGAGGCTGACTGCCGGCCTGAGAT","GACTGAGGCCTCGGAGGGGTTTAAGGAACGCGGGCCAGAGGCGGGCGGGGCCGAGGTTGGTGGAGTCCGAGGCCCGCTGGAAGCCTGAAGTGGGCGGTGGCTTCAGGTGGGCGGGGCCTATGGCCTCTTTCCCAGTGTCCGCTGCGCTGCCGGGCCCGCGAGGCCGAGCTCGCCAGCCGGATGTGTCGCCTGTTCTTCCCTGTTGCGTTCGGCACTGACTGGGGAGCTTGTATCTGGGCAAAAATGGAAACTTTTTAAAAAGACTGCCCTTACCGCGTGAGAGTGCGTGGCTTTTTCTCCACCAGGGGTTAATTAGGTGCTTGAACTTCCTCAGAAAAGCCAAGCCAGGGTTTATTTTCATAGTTCATACAAGTACTTCACGTGGTTAAAGAAGCCCATCCCGGCTGTGAGAATAGCGTGCAGTAGCTCGCGCGTTAAGAACGGGCAGAACGCCACTCAAAAGCACGTCGGGATGGTGGGTTTCGGATTGGGGTGAGTCCCGCCGGCGTTATTAAGGGCGCACCAGTGATCAGCTCAACACCGTGCTCACCAAAGAGGCCGACCGGGCCTCCCTGAAGTCAGTGTCCGACAAATAGGGAAACAAAGCGGTTTCCGGAAGGTTGCATGCTAGGGAAACAGGAGATCAGTTGTATCATGACTCGGAACCGTGAGGCGGGAATTACAACCTTTTTGTTCAAGTAAAAATAATGCAGTTAGTACAAAAAGATAATGATTATGGAAATAAGAGGTGCAGTCATTGAAGACGTTAGTCGTCCTGGAGGGAGTGTCACAAAGTATACGATGTTGAATTTGTCTTTGAAACTACAAGGCCATTTTTAAAAAGCCATTGAATGTGAGGTCATTCATTCTCATTGTGATGACATT","CATGTGGTATAGAATGGGCCTTTCATAAGTACTGACTACTTGGGTGGG"];
-    /// let gene: utils::gene::Gene = utils::gene::Gene::new(
-    ///                     "GRCh38",
-    ///                     "protein_coding",
-    ///                     "ENST00000285947.5",
-    ///                     "core",
-    ///                     "SET domain containing 9 [Source:HGNC Symbol;Acc:HGNC:28508]",
-    ///                     "SETD9",
-    ///                     "GACAGCCGTGACAGAGAAACTAGGCGGTCCGCTCCTGGGACGCGGTCTCGGGGGCGGGTTCGCCCCTGAGGCCCGGTGACCCTTCGCCCGCAACCAGGGCTAGCGCTTGTGTGCGCCCACGGAAGCACCCGAGCGACCGGAGAAAGAAAAAGTGGTCAAGGGGACCTCCTTCCAGCTCTACGCGCCTCTCATTCAGAGAGGTGAGGTCAGGAAAGGGGAAGGACGAAGCCCCAGAGCAACAGAAGTCAGGCGGTGCCAGGAACACTGAGAGCGGAGCCAGGGGAAGGCGGCCGAGCGCGGCCCCCTCTCCTCCCGGGCCGGGGCGGGCCCGAGGCCTCGATCCGCCTTCCCCGCGCCGTCCTGGTCACGGCCCCGCGGGGCAGCCATGCCTGGCCGTCTGCTGCGGGGCCTGTGGCAGCGATGGCGCCGTTACAAGTACCGCTTCGTTCCCTGGATCGCACTGAACCTAAGCCACAACCCGAGGTGAGAGGGCGGGACGGCAGAACGAGGGGCACCTGCCTTCGGTTCCCAGACGCCACCACGGCGGCGGGACGCAAAGCGGAGAGCCTGAGGCTGACTGCCGGCCTGAGATgggcgggcccgggtgggcagggactgaggtgggcgggccgggtgggcgggGACTGAGGCCTCGGAGGGGTTTAAGGAACGCGGGCCAGAGGCGGGCGGGGCCGAGGTTGGTGGAGTCCGAGGCCCGCTGGAAGCCTGAAGTGGGCGGTGGCTTCAGGTGGGCGGGGCCTATGGCCTCTTTCCCAGTGTCCGCTGCGCTGCCGGGCCCGCGAGGCCGAGCTCGCCAGCCGGATGTGTCGCCTGTTCTTCCCTGTTGCGTTCGGCACTGACTGGGGAGCTTGTATCTGGGCAAAAATGGAAACTTTTTAAAAAGACTGCCCTTACCGCGTGAGAGTGCGTGGCTTTTTCTCCACCAGGGGTTAATTAGGTGCTTGAACTTCCTCAGAAAAGCCAAGCCAGGGTTTATTTTCATAGTTCATACAAGTACTTCACGTGGTTAAAGAAGCCCATCCCGGCTGTGAGAATAGCGTGCAGTAGCTCGCGCGTTAAGAACGGGCAGAACGCCACTCAAAAGCACGTCGGGATGGTGGGTTTCGGATTGGGGTGAGTCCCGCCGGCGTTATTAAGGGCGCACCAGTGATCAGCTCAACACCGTGCTCACCAAAGAGGCCGACCGGGCCTCCCTGAAGTCAGTGTCCGACAAATAGGGAAACAAAGCGGTTTCCGGAAGGTTGCATGCTAGGGAAACAGGAGATCAGTTGTATCATGACTCGGAACCGTGAGGCGGGAATTACAACCTTTTTGTTCAAGTAAAAATAATGCAGTTAGTACAAAAAGATAATGATTATGGAAATAAGAGGTGCAGTCATTGAAGACGTTAGTCGTCCTGGAGGGAGTGTCACAAAGTATACGATGTTGAATTTGTCTTTGAAACTACAAGGCCATTTTTAAAAAGCCATTGAATGTGAGGTCATTCATTCTCATTGTGATGACATTcagcttggctgttgggtgtagtacgggaaagagctgaacttcgtttctgcctctggctctcccacttactggctttgggaccttgaacaagttccctaacaattttgctttccttgtctgcaaaaatggaataatgtaaggaccccacagCATGTGGTATAGAATGGGCCTTTCATAAGTACTGACTACTTGGGTGGG",
-    ///                     "56925532",
-    ///                     "ENSG00000155542",
-    ///                     "ensembl_havana_gene_homo_sapiens",
-    ///                     "Gene",
-    ///                     "5",
-    ///                     "ensembl_havana",
-    ///                     "homo_sapiens",
-    ///                     "56909260",
-    ///                     "1",
-    ///                     "12"
-    /// );
+    /// let gene: utils::gene::Gene = utils::gene::Gene::builder()
+    ///     .assembly_name("GRCh38")
+    ///     .biotype("protein_coding")
+    ///     .canonical_transcript("ENST00000285947.5")
+    ///     .db_type("core")
+    ///     .description("SET domain containing 9 [Source:HGNC Symbol;Acc:HGNC:28508]")
+    ///     .display_name("SETD9")
+    ///     .dna("GACAGCCGTGACAGAGAAACTAGGCGGTCCGCTCCTGGGACGCGGTCTCGGGGGCGGGTTCGCCCCTGAGGCCCGGTGACCCTTCGCCCGCAACCAGGGCTAGCGCTTGTGTGCGCCCACGGAAGCACCCGAGCGACCGGAGAAAGAAAAAGTGGTCAAGGGGACCTCCTTCCAGCTCTACGCGCCTCTCATTCAGAGAGGTGAGGTCAGGAAAGGGGAAGGACGAAGCCCCAGAGCAACAGAAGTCAGGCGGTGCCAGGAACACTGAGAGCGGAGCCAGGGGAAGGCGGCCGAGCGCGGCCCCCTCTCCTCCCGGGCCGGGGCGGGCCCGAGGCCTCGATCCGCCTTCCCCGCGCCGTCCTGGTCACGGCCCCGCGGGGCAGCCATGCCTGGCCGTCTGCTGCGGGGCCTGTGGCAGCGATGGCGCCGTTACAAGTACCGCTTCGTTCCCTGGATCGCACTGAACCTAAGCCACAACCCGAGGTGAGAGGGCGGGACGGCAGAACGAGGGGCACCTGCCTTCGGTTCCCAGACGCCACCACGGCGGCGGGACGCAAAGCGGAGAGCCTGAGGCTGACTGCCGGCCTGAGATgggcgggcccgggtgggcagggactgaggtgggcgggccgggtgggcgggGACTGAGGCCTCGGAGGGGTTTAAGGAACGCGGGCCAGAGGCGGGCGGGGCCGAGGTTGGTGGAGTCCGAGGCCCGCTGGAAGCCTGAAGTGGGCGGTGGCTTCAGGTGGGCGGGGCCTATGGCCTCTTTCCCAGTGTCCGCTGCGCTGCCGGGCCCGCGAGGCCGAGCTCGCCAGCCGGATGTGTCGCCTGTTCTTCCCTGTTGCGTTCGGCACTGACTGGGGAGCTTGTATCTGGGCAAAAATGGAAACTTTTTAAAAAGACTGCCCTTACCGCGTGAGAGTGCGTGGCTTTTTCTCCACCAGGGGTTAATTAGGTGCTTGAACTTCCTCAGAAAAGCCAAGCCAGGGTTTATTTTCATAGTTCATACAAGTACTTCACGTGGTTAAAGAAGCCCATCCCGGCTGTGAGAATAGCGTGCAGTAGCTCGCGCGTTAAGAACGGGCAGAACGCCACTCAAAAGCACGTCGGGATGGTGGGTTTCGGATTGGGGTGAGTCCCGCCGGCGTTATTAAGGGCGCACCAGTGATCAGCTCAACACCGTGCTCACCAAAGAGGCCGACCGGGCCTCCCTGAAGTCAGTGTCCGACAAATAGGGAAACAAAGCGGTTTCCGGAAGGTTGCATGCTAGGGAAACAGGAGATCAGTTGTATCATGACTCGGAACCGTGAGGCGGGAATTACAACCTTTTTGTTCAAGTAAAAATAATGCAGTTAGTACAAAAAGATAATGATTATGGAAATAAGAGGTGCAGTCATTGAAGACGTTAGTCGTCCTGGAGGGAGTGTCACAAAGTATACGATGTTGAATTTGTCTTTGAAACTACAAGGCCATTTTTAAAAAGCCATTGAATGTGAGGTCATTCATTCTCATTGTGATGACATTcagcttggctgttgggtgtagtacgggaaagagctgaacttcgtttctgcctctggctctcccacttactggctttgggaccttgaacaagttccctaacaattttgctttccttgtctgcaaaaatggaataatgtaaggaccccacagCATGTGGTATAGAATGGGCCTTTCATAAGTACTGACTACTTGGGTGGG".parse().unwrap())
+    ///     .end(56925532)
+    ///     .id("ENSG00000155542")
+    ///     .logic_name("ensembl_havana_gene_homo_sapiens")
+    ///     .object_type("Gene")
+    ///     .seq_region_name("5")
+    ///     .source("ensembl_havana")
+    ///     .species("homo_sapiens")
+    ///     .start(56909260)
+    ///     .strand(utils::seq::Strand::Forward)
+    ///     .version(12)
+    ///     .build();
     /// let result: Vec<String> = gene.non_repeat_regions().unwrap();
     /// assert_eq!(result, expected);
     /// ```
@@ -245,7 +575,8 @@ impl Gene {
         };
 
         let mut repeats: Vec<String> = Vec::new();
-        for matched_expression in regular_expression.find_iter(&self.dna) {
+        let dna: String = self.dna.to_string();
+        for matched_expression in regular_expression.find_iter(&dna) {
             repeats.push(matched_expression.as_str().to_string());
         };
 
@@ -253,29 +584,122 @@ impl Gene {
     }
 }
 
+/// A builder of a `Gene`, setting each field by name.
+///
+/// Coordinates, the strand and the version may be set with a value, or with an `Option` when they may be unknown.
+#[derive(Clone, Debug, Default)]
+pub struct GeneBuilder {
+    gene: Gene,
+}
+
+impl GeneBuilder {
+    pub fn assembly_name(mut self, assembly_name: &str) -> GeneBuilder {
+        self.gene.assembly_name = String::from(assembly_name);
+        self
+    }
+
+    pub fn biotype(mut self, biotype: &str) -> GeneBuilder {
+        self.gene.biotype = String::from(biotype);
+        self
+    }
+
+    pub fn canonical_transcript(mut self, canonical_transcript: &str) -> GeneBuilder {
+        self.gene.canonical_transcript = String::from(canonical_transcript);
+        self
+    }
+
+    pub fn db_type(mut self, db_type: &str) -> GeneBuilder {
+        self.gene.db_type = String::from(db_type);
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> GeneBuilder {
+        self.gene.description = String::from(description);
+        self
+    }
+
+    pub fn display_name(mut self, display_name: &str) -> GeneBuilder {
+        self.gene.display_name = String::from(display_name);
+        self
+    }
+
+    pub fn dna(mut self, dna: crate::seq::nucleotide::DnaSequence) -> GeneBuilder {
+        self.gene.dna = dna;
+        self
+    }
+
+    pub fn end(mut self, end: impl Into<Option<u64>>) -> GeneBuilder {
+        self.gene.end = end.into();
+        self
+    }
+
+    pub fn id(mut self, id: &str) -> GeneBuilder {
+        self.gene.id = String::from(id);
+        self
+    }
+
+    pub fn logic_name(mut self, logic_name: &str) -> GeneBuilder {
+        self.gene.logic_name = String::from(logic_name);
+        self
+    }
+
+    pub fn object_type(mut self, object_type: &str) -> GeneBuilder {
+        self.gene.object_type = String::from(object_type);
+        self
+    }
+
+    pub fn seq_region_name(mut self, seq_region_name: &str) -> GeneBuilder {
+        self.gene.seq_region_name = String::from(seq_region_name);
+        self
+    }
+
+    pub fn source(mut self, source: &str) -> GeneBuilder {
+        self.gene.source = String::from(source);
+        self
+    }
+
+    pub fn species(mut self, species: &str) -> GeneBuilder {
+        self.gene.species = String::from(species);
+        self
+    }
+
+    pub fn start(mut self, start: impl Into<Option<u64>>) -> GeneBuilder {
+        self.gene.start = start.into();
+        self
+    }
+
+    pub fn strand(mut self, strand: impl Into<Option<crate::seq::Strand>>) -> GeneBuilder {
+        self.gene.strand = strand.into();
+        self
+    }
+
+    pub fn transcripts(mut self, transcripts: Vec<crate::transcript::Transcript>) -> GeneBuilder {
+        self.gene.transcripts = transcripts;
+        self
+    }
+
+    pub fn version(mut self, version: impl Into<Option<u64>>) -> GeneBuilder {
+        self.gene.version = version.into();
+        self
+    }
+
+    pub fn xrefs(mut self, xrefs: Vec<crate::xref::ExternalReference>) -> GeneBuilder {
+        self.gene.xrefs = xrefs;
+        self
+    }
+
+    pub fn build(self) -> Gene {
+        self.gene
+    }
+}
+
 impl serde::ser::Serialize for Gene {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::ser::Serializer,
     {
         let mut serialized_gene = serializer.serialize_struct("Gene", 17)?;
-        serialized_gene.serialize_field("assembly_name", &self.assembly_name)?;
-        serialized_gene.serialize_field("biotype", &self.biotype)?;
-        serialized_gene.serialize_field("canonical_transcript", &self.canonical_transcript)?;
-        serialized_gene.serialize_field("db_type", &self.db_type)?;
-        serialized_gene.serialize_field("description", &self.description)?;
-        serialized_gene.serialize_field("display_name", &self.display_name)?;
-        serialized_gene.serialize_field("dna", &self.dna)?;
-        serialized_gene.serialize_field("end", &self.end)?;
-        serialized_gene.serialize_field("id", &self.id)?;
-        serialized_gene.serialize_field("logic_name", &self.logic_name)?;
-        serialized_gene.serialize_field("object_type", &self.object_type)?;
-        serialized_gene.serialize_field("seq_region_name", &self.seq_region_name)?;
-        serialized_gene.serialize_field("source", &self.source)?;
-        serialized_gene.serialize_field("species", &self.species)?;
-        serialized_gene.serialize_field("start", &self.start)?;
-        serialized_gene.serialize_field("strand", &self.strand)?;
-        serialized_gene.serialize_field("version", &self.version)?;
+        self.serialize_fields(&mut serialized_gene)?;
         serialized_gene.end()
     }
 }
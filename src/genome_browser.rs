@@ -1,171 +1,956 @@
 //! MIT license.
 
-const ENSEMBL_BASE_URL: &str = "https://rest.ensembl.org";
-const UCSC_GENOME_BASE_URL: &str = "https://api.genome.ucsc.edu";
+pub mod assembly;
+pub mod ensembl;
+pub mod local;
+pub mod ucsc;
+
+/// The base URL of the EnsEMBL REST API.
+pub const ENSEMBL_BASE_URL: &str = "https://rest.ensembl.org";
+/// The base URL of the EnsEMBL REST API for the GRCh37 human assembly.
+pub const ENSEMBL_GRCH37_BASE_URL: &str = "https://grch37.rest.ensembl.org";
+/// The base URL of the University of California Santa Cruz Genome Browser's REST API.
+pub const UCSC_GENOME_BASE_URL: &str = "https://api.genome.ucsc.edu";
+
+/// The future returned by a genome browser's request.
+pub type GenomeBrowserFuture<'a, T> = std::pin::Pin<Box<dyn std::future::Future<Output = Result<T, Box<dyn std::error::Error>>> + 'a>>;
+
+/// A source of genes and genomic sequence, such as the EnsEMBL REST API, the University of California Santa Cruz
+/// Genome Browser, a mirror of either, or local files.
+///
+/// Regions are 1-based and inclusive, and sequences are of the forward strand, as in EnsEMBL.
+/// Assemblies are named as in EnsEMBL (e.g. GRCh38); a genome browser translates them to its own names.
+pub trait GenomeBrowser {
+    /// Look up a gene by its identifier, without its DNA.
+    fn lookup_gene<'a>(&'a self, id: &'a str) -> GenomeBrowserFuture<'a, crate::gene::Gene>;
+
+    /// Fetch the forward strand sequence of a chromosome region of an assembly.
+    fn region_sequence<'a>(&'a self, assembly_name: &'a str, chromosome: &'a str, start: u64, end: u64) -> GenomeBrowserFuture<'a, String>;
+
+    /// List the names of the assemblies the genome browser serves.
+    fn assemblies(&self) -> GenomeBrowserFuture<'_, Vec<String>>;
+}
+
+/// Look up a gene with one genome browser and fetch its DNA from another, or from the same genome browser.
+///
+/// # Example
+///
+/// ```
+/// use std::io::Write;
+/// use utils::genome_browser::local::LocalGenomeBrowser;
+///
+/// let mut genes: tempfile::NamedTempFile = tempfile::NamedTempFile::new().unwrap();
+/// genes.write_all(b"assembly_name,id,seq_region_name,start,end,strand\nGRCh38,ENSG00000000001,2,3,8,-1\n").unwrap();
+/// let mut fasta: tempfile::NamedTempFile = tempfile::NamedTempFile::new().unwrap();
+/// fasta.write_all(b">2 chromosome:GRCh38:2:1:12:1\nNNGATTACANNN\n").unwrap();
+///
+/// let browser: LocalGenomeBrowser = LocalGenomeBrowser::new("GRCh38")
+///     .with_genes(genes.path()).unwrap()
+///     .with_fasta(fasta.path()).unwrap();
+/// let runtime = actix_web::rt::Runtime::new().unwrap();
+/// let gene: utils::gene::Gene = runtime.block_on(utils::genome_browser::search(&browser, &browser, "ENSG00000000001")).unwrap();
+/// assert_eq!(gene.get_dna().to_string(), "GATTAC");
+/// assert_eq!(gene.get_oriented_dna(), "GTAATC");
+/// ```
+pub async fn search(
+    gene_browser: &dyn GenomeBrowser,
+    sequence_browser: &dyn GenomeBrowser,
+    id: &str,
+) -> Result<crate::gene::Gene, Box<dyn std::error::Error>> {
+    let gene: crate::gene::Gene = gene_browser.lookup_gene(id).await?;
+    let (Some(start), Some(end)) = (gene.get_start(), gene.get_end()) else {
+        eprintln!("{{\"id\": \"{}\", \"error\": \"The gene's start and end are unknown.\"}}", id);
+        return Err(Box::new(std::io::Error::new(std::io::ErrorKind::InvalidData, "the gene's start and end are unknown")));
+    };
+    let dna: crate::seq::nucleotide::DnaSequence = sequence_browser
+        .region_sequence(gene.get_assembly_name(), gene.get_seq_region_name(), start, end)
+        .await?
+        .parse()?;
+    Ok(gene.with_dna(dna))
+}
+
+/// A gene as described by the EnsEMBL REST API's `/lookup/id` endpoint.
+///
+/// Every field is optional, as EnsEMBL omits, or returns `null` for, fields without a value.
+///
+/// # Example
+///
+/// ```
+/// let json: &str = r#"{"assembly_name": "GRCh38", "display_name": "SETD9", "id": "ENSG00000155542", "seq_region_name": "5",
+///                      "start": 56909260, "end": 56925532, "strand": 1, "version": 12, "description": null}"#;
+/// let lookup: utils::genome_browser::EnsemblLookup = serde_json::from_str(json).unwrap();
+/// let gene: utils::gene::Gene = lookup.to_gene(utils::seq::nucleotide::DnaSequence::new());
+/// assert_eq!(gene.get_start(), Some(56909260));
+/// assert_eq!(gene.get_strand(), Some(utils::seq::Strand::Forward));
+/// assert_eq!(gene.get_description(), "");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize)]
+pub struct EnsemblLookup {
+    pub assembly_name: Option<String>,
+    pub biotype: Option<String>,
+    pub canonical_transcript: Option<String>,
+    pub db_type: Option<String>,
+    pub description: Option<String>,
+    pub display_name: Option<String>,
+    pub end: Option<u64>,
+    pub id: Option<String>,
+    pub logic_name: Option<String>,
+    pub object_type: Option<String>,
+    pub seq_region_name: Option<String>,
+    pub source: Option<String>,
+    pub species: Option<String>,
+    pub start: Option<u64>,
+    pub strand: Option<crate::seq::Strand>,
+    /// The gene's transcripts, present when the lookup is expanded.
+    #[serde(rename = "Transcript", default)]
+    pub transcripts: Vec<crate::transcript::Transcript>,
+    pub version: Option<u64>,
+}
+
+impl EnsemblLookup {
+    pub fn get_assembly_name(&self) -> &str {
+        self.assembly_name.as_deref().unwrap_or("")
+    }
+
+    pub fn get_seq_region_name(&self) -> &str {
+        self.seq_region_name.as_deref().unwrap_or("")
+    }
+
+    pub fn get_start(&self) -> String {
+        self.start.map(|start| start.to_string()).unwrap_or_default()
+    }
+
+    pub fn get_end(&self) -> String {
+        self.end.map(|end| end.to_string()).unwrap_or_default()
+    }
+
+    /// Return the lookup with its region expanded by `expand_5prime` bases upstream and `expand_3prime` bases downstream,
+    /// as EnsEMBL expands the genomic sequence of a feature. Upstream is towards the start of a forward strand gene
+    /// and towards the end of a reverse strand gene; the start is not expanded past the first base of its region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let lookup = utils::genome_browser::EnsemblLookup { start: Some(1000), end: Some(2000), strand: Some(utils::seq::Strand::Reverse), ..Default::default() };
+    /// let expanded: utils::genome_browser::EnsemblLookup = lookup.expanded(500, 1500);
+    /// assert_eq!((expanded.start, expanded.end), (Some(1), Some(2500)));
+    /// ```
+    pub fn expanded(&self, expand_5prime: u64, expand_3prime: u64) -> EnsemblLookup {
+        let (upstream_of_start, downstream_of_end): (u64, u64) = match self.strand {
+            Some(crate::seq::Strand::Reverse) => (expand_3prime, expand_5prime),
+            _ => (expand_5prime, expand_3prime),
+        };
+        EnsemblLookup {
+            start: self.start.map(|start| start.saturating_sub(upstream_of_start).max(1)),
+            end: self.end.map(|end| end + downstream_of_end),
+            ..self.clone()
+        }
+    }
+
+    /// Return the gene described by the lookup with its DNA.
+    pub fn to_gene(&self, dna: crate::seq::nucleotide::DnaSequence) -> crate::gene::Gene {
+        crate::gene::Gene::builder()
+            .assembly_name(self.get_assembly_name())
+            .biotype(self.biotype.as_deref().unwrap_or(""))
+            .canonical_transcript(self.canonical_transcript.as_deref().unwrap_or(""))
+            .db_type(self.db_type.as_deref().unwrap_or(""))
+            .description(self.description.as_deref().unwrap_or(""))
+            .display_name(self.display_name.as_deref().unwrap_or(""))
+            .dna(dna)
+            .end(self.end)
+            .id(self.id.as_deref().unwrap_or(""))
+            .logic_name(self.logic_name.as_deref().unwrap_or(""))
+            .object_type(self.object_type.as_deref().unwrap_or(""))
+            .seq_region_name(self.get_seq_region_name())
+            .source(self.source.as_deref().unwrap_or(""))
+            .species(self.species.as_deref().unwrap_or(""))
+            .start(self.start)
+            .strand(self.strand)
+            .transcripts(self.transcripts.clone())
+            .version(self.version)
+            .build()
+    }
+
+    /// Read a lookup from the YAML text of the HTML view of `/lookup/id`, where numeric
+    /// chromosome names and strands may be parsed as numbers.
+    fn from_yaml(yaml: &serde_yaml::Value) -> EnsemblLookup {
+        let text = |key: &str| -> Option<String> {
+            match &yaml[key] {
+                serde_yaml::Value::String(text) => Some(text.clone()),
+                serde_yaml::Value::Number(number) => Some(number.to_string()),
+                _ => None,
+            }
+        };
+
+        EnsemblLookup {
+            assembly_name: text("assembly_name"),
+            biotype: text("biotype"),
+            canonical_transcript: text("canonical_transcript"),
+            db_type: text("db_type"),
+            description: text("description"),
+            display_name: text("display_name"),
+            end: yaml["end"].as_u64(),
+            id: text("id"),
+            logic_name: text("logic_name"),
+            object_type: text("object_type"),
+            seq_region_name: text("seq_region_name"),
+            source: text("source"),
+            species: text("species"),
+            start: yaml["start"].as_u64(),
+            strand: text("strand").and_then(|strand| strand.parse::<crate::seq::Strand>().ok()),
+            transcripts: serde_yaml::from_value(yaml["Transcript"].clone()).unwrap_or_default(),
+            version: yaml["version"].as_u64(),
+        }
+    }
+}
+
+/// The maximum number of identifiers EnsEMBL accepts in a single `POST /lookup/id` request.
+pub const ENSEMBL_LOOKUP_BATCH_SIZE: usize = 1_000;
+
+pub struct EnsemblBatchResponse {
+    /// Each resolved identifier and its gene, in the order the identifiers were requested.
+    pub genes: Vec<(String, crate::gene::Gene)>,
+    /// The identifiers EnsEMBL could not resolve.
+    pub unresolved: Vec<String>,
+    /// The sequences fetched from EnsEMBL, in the order of the genes they were requested by.
+    pub sequences: Vec<EnsemblSequence>,
+}
+
 pub struct GenomeBrowserResponse {
     pub gene: crate::gene::Gene,
     pub timestamp: std::time::SystemTime
 }
 
-/// Search genome browsers for gene information.
+/// Search genome browsers for gene information: look up a gene at an EnsEMBL genome browser, and fetch its DNA from
+/// `sequence_browser`, such as the University of California Santa Cruz Genome Browser, or not at all if it is `None`.
+///
+/// Requests to EnsEMBL are counted against its daily limit in `quota::global()`, and refused once it is used up.
 /// 
 /// # Example
 /// 
 /// ```
+/// use utils::genome_browser::{ensembl::EnsemblGenomeBrowser, ucsc::UcscGenomeBrowser};
+///
 /// let runtime = actix_web::rt::Runtime::new().unwrap();
 /// assert!(
 /// runtime.block_on(async {
-///     let client: awc::Client = awc::Client::default();
+///     let ensembl: EnsemblGenomeBrowser = EnsemblGenomeBrowser::new(awc::Client::default());
+///     let ucsc: UcscGenomeBrowser = UcscGenomeBrowser::new(awc::Client::default());
 ///     let ensembl_id: String = String::from("ENSG00000155542");
-///     let query_for_DNA_please: bool = true;
-///     let last_request_made: Option<std::time::SystemTime> = None;
-///     let number_of_requests_made: Option<u32> = Some(0);
-///     let genome_browser_response = utils::genome_browser::ensembl_search(&client,
+///     let genome_browser_response = utils::genome_browser::ensembl_search(&ensembl,
 ///                                                               &ensembl_id,
-///                                                               query_for_DNA_please,
-///                                                               last_request_made,
-///                                                               number_of_requests_made).await.unwrap();
+///                                                               Some(&ucsc)).await.unwrap();
 ///     String::from(genome_browser_response.gene.get_display_name())
 ///  }).eq(&String::from("SETD9")));
 /// ```
 pub async fn ensembl_search(
-    client: &awc::Client,
+    ensembl: &ensembl::EnsemblGenomeBrowser,
     ensembl_id: &str,
-    query_dna: bool,
-    last_request_made: Option<std::time::SystemTime>,
-    number_of_requests_made: Option<u32>
+    sequence_browser: Option<&dyn GenomeBrowser>
 ) -> Result<GenomeBrowserResponse, Box<dyn std::error::Error>> {
-    let resource: String = format!("{ENSEMBL_BASE_URL}/lookup/id/{ensembl_id}");
+    let resource: String = format!("{}/lookup/id/{ensembl_id}", ensembl.get_base_url());
+    ensembl_lookup_search(ensembl.get_client(), ensembl_id, &resource, sequence_browser).await
+}
 
-    // A maximum of 5_000 requests can be made per day.
-    // If the number of requests made is supplied and exceeds 5_000 then exit.
-    if number_of_requests_made.is_some() && number_of_requests_made.unwrap() >= 5_000 {
-        eprintln!("{{\"error\": \"The EnsEMBL maximum number of requests (5,000) has been exceeded.\"}}");
-        return Err(Box::new(std::io::Error::from(std::io::ErrorKind::ConnectionRefused)));
-    }
+/// Search genome browsers for gene information, expanding the gene's transcripts with their exons, translations
+/// and untranslated regions.
+///
+/// The DNA queried spans the gene, and so every transcript's exons, so that each transcript's cDNA and coding
+/// sequence can be assembled from it.
+///
+/// # Example
+///
+/// ```no_run
+/// let runtime = actix_web::rt::Runtime::new().unwrap();
+/// let gene: utils::gene::Gene = runtime.block_on(async {
+///     let ensembl = utils::genome_browser::ensembl::EnsemblGenomeBrowser::new(awc::Client::default());
+///     let ucsc = utils::genome_browser::ucsc::UcscGenomeBrowser::new(awc::Client::default());
+///     utils::genome_browser::ensembl_expanded_search(&ensembl, "ENSG00000155542", Some(&ucsc)).await.unwrap().gene
+/// });
+/// let transcript: &utils::transcript::Transcript = gene.find_canonical_transcript().unwrap();
+/// assert!(gene.transcript_cds(transcript).unwrap().starts_with("ATG"));
+/// ```
+pub async fn ensembl_expanded_search(
+    ensembl: &ensembl::EnsemblGenomeBrowser,
+    ensembl_id: &str,
+    sequence_browser: Option<&dyn GenomeBrowser>
+) -> Result<GenomeBrowserResponse, Box<dyn std::error::Error>> {
+    let resource: String = format!("{}/lookup/id/{ensembl_id}?expand=1;utr=1", ensembl.get_base_url());
+    ensembl_lookup_search(ensembl.get_client(), ensembl_id, &resource, sequence_browser).await
+}
 
-    // A maximum of one request can be made to the EnsEMBL Genome Browser approximately every 67 milliseconds.
-    // Wait 67 milliseconds if the time the last request is made is supplied.
-    if last_request_made.is_some() {
-        crate::time::sleep_until_time_elapsed(last_request_made.unwrap(), 67 as u64);
-    }
+/// Look up an EnsEMBL identifier at `resource`, a `/lookup/id` URL, and fetch the gene's DNA from `sequence_browser`, if any.
+async fn ensembl_lookup_search(
+    client: &awc::Client,
+    ensembl_id: &str,
+    resource: &str,
+    sequence_browser: Option<&dyn GenomeBrowser>
+) -> Result<GenomeBrowserResponse, Box<dyn std::error::Error>> {
 
-    let html = match crate::web::get_html_body(client, &resource[..]).await {
-        Ok(html) => html,
+    // Request the lookup as JSON, falling back to parsing the pre-formatted text of the lookup's HTML view as YAML.
+    let lookup: EnsemblLookup = match crate::web::get_json::<serde_json::Value>(client, resource).await {
+        Ok(json) => {
+            if let Some(error) = json["error"].as_str() {
+                eprintln!("{{\"id\":\"{}\", \"error\":\"{}\"}}", ensembl_id, error);
+                return Err(Box::new(std::io::Error::new(std::io::ErrorKind::NotFound, error)));
+            }
+            match serde_json::from_value::<EnsemblLookup>(json) {
+                Ok(lookup) => lookup,
+                Err(error) => {
+                    eprintln!("{{\"id\":\"{}\", \"error\":\"{}\"}}", ensembl_id, error);
+                    ensembl_html_lookup(client, ensembl_id, resource).await?
+                }
+            }
+        }
         Err(error) => {
             eprintln!("{{\"id\":\"{}\", \"error\":\"{}\"}}", ensembl_id, error);
-            String::from("")
+            // Only a response that is not JSON is looked up again; a request that failed is not retried through the HTML view.
+            if !error.is::<serde_json::Error>() {
+                return Err(error);
+            }
+            ensembl_html_lookup(client, ensembl_id, resource).await?
         }
     };
 
-    let parsed = crate::html::parse_pre_elements(&html[..]);
+    let dna = match sequence_browser {
+        Some(sequence_browser) => region_dna(sequence_browser, &lookup).await?,
+        None => crate::seq::nucleotide::DnaSequence::new(),
+    };
 
-    let ensembl_yaml: Result<serde_yaml::Value, serde_yaml::Error> =
-        serde_yaml::from_str(&parsed[..]);
+    Ok(GenomeBrowserResponse {
+        gene: lookup.to_gene(dna),
+        timestamp: std::time::SystemTime::now()
+    })
+}
 
-    match ensembl_yaml {
-        Ok(yaml) => {
-            let assembly_name = String::from(yaml["assembly_name"].as_str().unwrap_or(""));
-            let biotype = String::from(yaml["biotype"].as_str().unwrap_or(""));
-            let canonical_transcript =
-                String::from(yaml["canonical_transcript"].as_str().unwrap_or(""));
-            let db_type = String::from(yaml["db_type"].as_str().unwrap_or(""));
-            let description = String::from(yaml["description"].as_str().unwrap_or(""));
-            let display_name = String::from(yaml["display_name"].as_str().unwrap_or(""));
-            let id = String::from(yaml["id"].as_str().unwrap_or(""));
-            let logic_name = String::from(yaml["logic_name"].as_str().unwrap_or(""));
-            let object_type = String::from(yaml["object_type"].as_str().unwrap_or(""));
-            let source = String::from(yaml["source"].as_str().unwrap_or(""));
-            let species = String::from(yaml["species"].as_str().unwrap_or(""));
-
-            let end = match yaml["end"].as_u64() {
-                Some(number) => number.to_string(),
-                None => String::from(""),
-            };
-            let start = match yaml["start"].as_u64() {
-                Some(number) => number.to_string(),
-                None => String::from(""),
-            };
-            let version = match yaml["version"].as_u64() {
-                Some(number) => number.to_string(),
-                None => String::from(""),
-            };
-
-            let seq_region_name: String;
-            if yaml["seq_region_name"].is_number() {
-                seq_region_name = match yaml["seq_region_name"].as_u64() {
-                    Some(number) => number.to_string(),
-                    None => String::from(""),
+/// Search the EnsEMBL Genome Browser for many genes at once, posting up to 1,000 identifiers per request.
+///
+/// Genes are returned paired with the identifier they were requested by, in the order of `ensembl_ids`.
+/// Identifiers EnsEMBL could not resolve are returned separately.
+///
+/// Sequences are fetched from `sequence_source`, or not at all if it is `None`. Genomic sequences are stored as
+/// each gene's DNA, on the forward strand; every sequence fetched from EnsEMBL is also returned in `sequences`.
+/// A gene whose genomic sequence is expanded with `expand_5prime` or `expand_3prime` is returned with its start and end
+/// expanded to the region of its DNA.
+pub async fn ensembl_batch_search(
+    ensembl: &ensembl::EnsemblGenomeBrowser,
+    ensembl_ids: &[String],
+    sequence_source: Option<&SequenceSource<'_>>
+) -> Result<EnsemblBatchResponse, Box<dyn std::error::Error>> {
+    let resource: String = format!("{}/lookup/id", ensembl.get_base_url());
+    let mut response: EnsemblBatchResponse = EnsemblBatchResponse {
+        genes: Vec::new(),
+        unresolved: Vec::new(),
+        sequences: Vec::new(),
+    };
+
+    for batch in ensembl_ids.chunks(ENSEMBL_LOOKUP_BATCH_SIZE) {
+        let (lookups, not_cached) = ensembl_cached_lookups(ensembl.get_client(), &resource, batch).await?;
+
+        // Fetch the sequences of the resolved identifiers from EnsEMBL, in batches of their own.
+        let mut sequences: std::collections::HashMap<String, Vec<EnsemblSequence>> = std::collections::HashMap::new();
+        if let Some(SequenceSource::Ensembl(options)) = sequence_source {
+            let resolved: Vec<String> = batch.iter().filter(|id| matches!(lookups.get(*id), Some(Some(_)))).cloned().collect();
+            let sequence_response: EnsemblSequenceResponse = ensembl_sequence_search(ensembl, &resolved, options).await?;
+            for sequence in sequence_response.sequences {
+                sequences.entry(String::from(sequence.get_query())).or_default().push(sequence);
+            }
+        }
+
+        for ensembl_id in batch {
+            match lookups.get(ensembl_id) {
+                Some(Some(lookup)) => {
+                    let mut lookup: std::borrow::Cow<EnsemblLookup> = std::borrow::Cow::Borrowed(lookup);
+                    let dna = match sequence_source {
+                        Some(SequenceSource::Region(sequence_browser)) => {
+                            // A region the genome browser rejects, or responds to without DNA, or that is not cached
+                            // offline, leaves the gene without DNA, whereas the genome browser failing after every
+                            // attempt ends the search.
+                            match region_dna(*sequence_browser, &lookup).await {
+                                Ok(dna) => dna,
+                                Err(error) if error.downcast_ref::<crate::web::FetchError>().is_some_and(|error| error.is_transient()) => return Err(error),
+                                Err(_) => crate::seq::nucleotide::DnaSequence::new(),
+                            }
+                        }
+                        Some(SequenceSource::Ensembl(options)) if options.sequence_type == SequenceType::Genomic => {
+                            // EnsEMBL returns a gene's genomic sequence on the gene's strand, expanded as requested,
+                            // so the gene's region is expanded with it.
+                            if options.expand_5prime > 0 || options.expand_3prime > 0 {
+                                lookup = std::borrow::Cow::Owned(lookup.expanded(options.expand_5prime, options.expand_3prime));
+                            }
+                            let sequence: &str = sequences.get(ensembl_id).and_then(|sequences| sequences.first()).map(|sequence| sequence.get_sequence()).unwrap_or("");
+                            match sequence.parse::<crate::seq::nucleotide::DnaSequence>() {
+                                Ok(dna) if lookup.strand == Some(crate::seq::Strand::Reverse) => dna.reverse_complement(),
+                                Ok(dna) => dna,
+                                Err(error) => {
+                                    eprintln!("{{\"id\":\"{}\", \"error\":\"{}\"}}", ensembl_id, error);
+                                    crate::seq::nucleotide::DnaSequence::new()
+                                }
+                            }
+                        }
+                        _ => crate::seq::nucleotide::DnaSequence::new(),
+                    };
+                    response.genes.push((ensembl_id.clone(), lookup.to_gene(dna)));
+                    response.sequences.extend(sequences.remove(ensembl_id).unwrap_or_default());
                 }
-            } else if yaml["seq_region_name"].is_string() {
-                seq_region_name = String::from(yaml["seq_region_name"].as_str().unwrap_or(""))
-            } else {
-                seq_region_name = String::from("");
+                _ if not_cached.contains(ensembl_id) => (),
+                _ => response.unresolved.push(ensembl_id.clone()),
+            }
+        }
+    }
+
+    Ok(response)
+}
+
+/// Look up a batch of identifiers at `resource`, `/lookup/id`, serving the identifiers looked up before from
+/// `cache::global()` and posting the others, so that overlapping lists of identifiers share their lookups.
+///
+/// Returns the lookups, and the identifiers skipped because they are not cached and the cache is offline.
+async fn ensembl_cached_lookups(
+    client: &awc::Client,
+    resource: &str,
+    batch: &[String],
+) -> Result<(std::collections::HashMap<String, Option<EnsemblLookup>>, Vec<String>), Box<dyn std::error::Error>> {
+    // Each lookup is cached as `get_json` caches the lookup of a single identifier.
+    let key = |ensembl_id: &str| crate::web::json_cache_key(&format!("{resource}/{ensembl_id}"));
+    let cache: Option<&crate::cache::HttpCache> = crate::cache::global();
+
+    let mut lookups: std::collections::HashMap<String, Option<EnsemblLookup>> = std::collections::HashMap::new();
+    let mut uncached: Vec<&String> = Vec::new();
+    for ensembl_id in batch {
+        let cached: Option<EnsemblLookup> = cache
+            .and_then(|cache| cache.get(&key(ensembl_id)))
+            .and_then(|body| serde_json::from_slice(&body).ok());
+        match cached {
+            Some(lookup) => {
+                lookups.insert(ensembl_id.clone(), Some(lookup));
             }
+            None => uncached.push(ensembl_id),
+        }
+    }
+    if uncached.is_empty() {
+        return Ok((lookups, Vec::new()));
+    }
 
-            let strand: String;
-            if yaml["strand"].is_number() {
-                strand = match yaml["strand"].as_i64() {
-                    Some(number) => number.to_string(),
-                    None => String::from(""),
+    // EnsEMBL responds with an object keyed by the requested identifiers, with `null` for identifiers it could not resolve.
+    let posted: std::collections::HashMap<String, serde_json::Value> =
+        match crate::web::post_json(client, resource, &serde_json::json!({ "ids": uncached })).await {
+            Ok(posted) => posted,
+            Err(error) if error.is::<crate::cache::NotCachedError>() => {
+                for ensembl_id in uncached.iter() {
+                    eprintln!("{{\"id\": \"{}\", \"error\": \"The lookup is not cached, and requests are not made offline.\"}}", ensembl_id);
                 }
-            } else if yaml["strand"].is_string() {
-                strand = String::from(yaml["strand"].as_str().unwrap_or(""))
-            } else {
-                strand = String::from("");
+                return Ok((lookups, uncached.into_iter().cloned().collect()));
+            }
+            Err(error) => return Err(error),
+        };
+    for (ensembl_id, json) in posted {
+        // A lookup that cannot be read leaves its identifier unresolved, rather than failing the rest of the batch.
+        let lookup: Option<EnsemblLookup> = match serde_json::from_value(json.clone()) {
+            Ok(lookup) => lookup,
+            Err(error) => {
+                eprintln!("{{\"id\": \"{}\", \"error\": \"{}\"}}", ensembl_id, error);
+                None
+            }
+        };
+        if let (Some(cache), Some(_)) = (cache, &lookup) {
+            if let Err(error) = cache.put(&key(&ensembl_id), json.to_string().as_bytes()) {
+                eprintln!("{{\"directory\": \"{}\", \"error\": \"{}\"}}", cache.get_directory().display(), error);
             }
+        }
+        lookups.insert(ensembl_id, lookup);
+    }
+    Ok((lookups, Vec::new()))
+}
 
-            let dna = match query_dna {
-                true => {
-                    let json: String = ucsc_genome_browser_search(client, &assembly_name, &start, &end, &seq_region_name, last_request_made)
-                    .await
-                    .unwrap_or(String::from(""));
+/// Where the sequences of genes are fetched from.
+#[derive(Clone)]
+pub enum SequenceSource<'a> {
+    /// The genomic DNA of each gene's region, from a genome browser such as the University of California Santa Cruz Genome Browser.
+    Region(&'a dyn GenomeBrowser),
+    /// Sequences from EnsEMBL's `/sequence/id`.
+    Ensembl(EnsemblSequenceOptions),
+}
 
-                    let json_result: Result<serde_json::Value, serde_json::Error> =
-                    serde_json::from_str(&json[..]);
+/// The type of sequence EnsEMBL's `/sequence/id` returns.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SequenceType {
+    /// The DNA of the gene, or transcript, including introns.
+    #[default]
+    Genomic,
+    /// The spliced transcripts.
+    Cdna,
+    /// The coding sequences of the transcripts.
+    Cds,
+    /// The translations of the transcripts.
+    Protein,
+}
 
-                    let dna = match json_result {
-                        Ok(json) => {
-                            let dna = String::from(json["dna"].as_str().unwrap_or(""));
-                            dna
-                        }
-                        Err(error) => {
-                            eprintln!(
-                                "{{\"assembly_name\":\"{}\", \"start\":{}, \"end\":{}, \"error\":\"{}\"}}",
-                                assembly_name, start, end, error
-                            );
-                            String::from("")
-                        }
-                    };
+impl std::str::FromStr for SequenceType {
+    type Err = std::io::Error;
 
-                    dna
-                },
-                false => String::from(""),
-            };
-
-            Ok(GenomeBrowserResponse {
-                gene: crate::gene::Gene::new (
-                &assembly_name,
-                &biotype,
-                &canonical_transcript,
-                &db_type,
-                &description,
-                &display_name,
-                &dna,
-                &end,
-                &id,
-                &logic_name,
-                &object_type,
-                &seq_region_name,
-                &source,
-                &species,
-                &start,
-                &strand,
-                &version,
-                ),
-                timestamp: std::time::SystemTime::now()
-            })
+    fn from_str(sequence_type: &str) -> Result<Self, Self::Err> {
+        match sequence_type {
+            "genomic" => Ok(SequenceType::Genomic),
+            "cdna" => Ok(SequenceType::Cdna),
+            "cds" => Ok(SequenceType::Cds),
+            "protein" => Ok(SequenceType::Protein),
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("{sequence_type} is not a sequence type (genomic, cdna, cds, protein)"),
+            )),
         }
+    }
+}
+
+impl std::fmt::Display for SequenceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SequenceType::Genomic => write!(f, "genomic"),
+            SequenceType::Cdna => write!(f, "cdna"),
+            SequenceType::Cds => write!(f, "cds"),
+            SequenceType::Protein => write!(f, "protein"),
+        }
+    }
+}
+
+/// How EnsEMBL masks repeats in genomic sequences.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SequenceMask {
+    /// Repeats in lowercase.
+    Soft,
+    /// Repeats replaced by N.
+    Hard,
+}
+
+impl std::str::FromStr for SequenceMask {
+    type Err = std::io::Error;
+
+    fn from_str(mask: &str) -> Result<Self, Self::Err> {
+        match mask {
+            "soft" => Ok(SequenceMask::Soft),
+            "hard" => Ok(SequenceMask::Hard),
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("{mask} is not a mask (soft, hard)"),
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for SequenceMask {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SequenceMask::Soft => write!(f, "soft"),
+            SequenceMask::Hard => write!(f, "hard"),
+        }
+    }
+}
+
+/// The options of a request to EnsEMBL's `/sequence/id`.
+///
+/// # Example
+///
+/// ```
+/// use utils::genome_browser::{EnsemblSequenceOptions, SequenceMask, SequenceType};
+///
+/// let options = EnsemblSequenceOptions {
+///     sequence_type: SequenceType::Genomic,
+///     mask: Some(SequenceMask::Soft),
+///     expand_5prime: 500,
+///     expand_3prime: 0,
+/// };
+/// assert_eq!(options.to_query(), "type=genomic;multiple_sequences=1;mask=soft;expand_5prime=500");
+/// assert_eq!(EnsemblSequenceOptions::default().to_query(), "type=genomic;multiple_sequences=1");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EnsemblSequenceOptions {
+    pub sequence_type: SequenceType,
+    /// Repeat masking of genomic sequences, or none.
+    pub mask: Option<SequenceMask>,
+    /// The number of bases to extend genomic sequences upstream, on the 5' end.
+    pub expand_5prime: u64,
+    /// The number of bases to extend genomic sequences downstream, on the 3' end.
+    pub expand_3prime: u64,
+}
+
+impl EnsemblSequenceOptions {
+    /// The options as the query string of a `/sequence/id` URL.
+    ///
+    /// Multiple sequences are always allowed, so that the cDNA, coding sequences and proteins of every transcript of a gene are returned.
+    pub fn to_query(&self) -> String {
+        let mut query: String = format!("type={};multiple_sequences=1", self.sequence_type);
+        if let Some(mask) = self.mask {
+            query.push_str(&format!(";mask={mask}"));
+        }
+        if self.expand_5prime > 0 {
+            query.push_str(&format!(";expand_5prime={}", self.expand_5prime));
+        }
+        if self.expand_3prime > 0 {
+            query.push_str(&format!(";expand_3prime={}", self.expand_3prime));
+        }
+        query
+    }
+}
+
+/// A sequence returned by EnsEMBL's `/sequence/id`.
+///
+/// # Example
+///
+/// ```
+/// let json: &str = r#"{"query": "ENSG00000000003", "id": "ENST00000373020", "desc": null, "molecule": "protein", "seq": "MASPSRRLQTK"}"#;
+/// let sequence: utils::genome_browser::EnsemblSequence = serde_json::from_str(json).unwrap();
+/// let record: utils::seq::io::FastaRecord = sequence.to_fasta_record();
+/// assert_eq!(record.get_id(), "ENST00000373020");
+/// assert_eq!(record.get_description(), "ENSG00000000003 protein");
+/// assert_eq!(record.get_sequence(), "MASPSRRLQTK");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize)]
+pub struct EnsemblSequence {
+    #[serde(default)]
+    query: Option<String>,
+    id: String,
+    #[serde(default)]
+    desc: Option<String>,
+    #[serde(default)]
+    molecule: Option<String>,
+    seq: String,
+}
+
+impl EnsemblSequence {
+    /// The identifier the sequence was requested by, such as a gene's identifier for the cDNA of its transcripts.
+    pub fn get_query(&self) -> &str {
+        self.query.as_deref().unwrap_or(&self.id)
+    }
+
+    /// The identifier of the sequence's feature.
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    /// EnsEMBL's description of the sequence, such as the region of a genomic sequence.
+    pub fn get_description(&self) -> &str {
+        self.desc.as_deref().unwrap_or("")
+    }
+
+    /// The kind of molecule: `dna` or `protein`.
+    pub fn get_molecule(&self) -> &str {
+        self.molecule.as_deref().unwrap_or("")
+    }
+
+    pub fn get_sequence(&self) -> &str {
+        &self.seq
+    }
+
+    /// Return the sequence as a FASTA record, described by the identifier it was requested by,
+    /// its kind of molecule and EnsEMBL's description.
+    pub fn to_fasta_record(&self) -> crate::seq::io::FastaRecord {
+        let description: Vec<&str> = [self.get_query(), self.get_molecule(), self.get_description()]
+            .into_iter()
+            .filter(|field| !field.is_empty())
+            .collect();
+        crate::seq::io::FastaRecord::new(&self.id, &description.join(" "), &self.seq)
+    }
+}
+
+/// The maximum number of identifiers EnsEMBL accepts in a single `POST /sequence/id` request.
+pub const ENSEMBL_SEQUENCE_BATCH_SIZE: usize = 50;
+
+pub struct EnsemblSequenceResponse {
+    /// The sequences, in the order of the identifiers they were requested by.
+    pub sequences: Vec<EnsemblSequence>,
+}
+
+/// Fetch sequences of EnsEMBL identifiers from EnsEMBL's `/sequence/id`, posting up to 50 identifiers per request.
+///
+/// Unlike the University of California Santa Cruz Genome Browser, EnsEMBL serves sequences at its own request rate
+/// and for every assembly it hosts. Genomic sequences are returned on the strand of their feature.
+///
+/// # Example
+///
+/// ```no_run
+/// use utils::genome_browser::{ensembl::EnsemblGenomeBrowser, EnsemblSequenceOptions, SequenceType};
+///
+/// let runtime = actix_web::rt::Runtime::new().unwrap();
+/// let response = runtime.block_on(async {
+///     let ensembl: EnsemblGenomeBrowser = EnsemblGenomeBrowser::new(awc::Client::default());
+///     let options = EnsemblSequenceOptions { sequence_type: SequenceType::Protein, ..Default::default() };
+///     utils::genome_browser::ensembl_sequence_search(&ensembl, &[String::from("ENSG00000000003")], &options).await.unwrap()
+/// });
+/// assert!(response.sequences.iter().all(|sequence| sequence.get_molecule() == "protein"));
+/// ```
+pub async fn ensembl_sequence_search(
+    ensembl: &ensembl::EnsemblGenomeBrowser,
+    ensembl_ids: &[String],
+    options: &EnsemblSequenceOptions
+) -> Result<EnsemblSequenceResponse, Box<dyn std::error::Error>> {
+    let resource: String = format!("{}/sequence/id?{}", ensembl.get_base_url(), options.to_query());
+    let mut response: EnsemblSequenceResponse = EnsemblSequenceResponse { sequences: Vec::new() };
+
+    for batch in ensembl_ids.chunks(ENSEMBL_SEQUENCE_BATCH_SIZE) {
+        let json: serde_json::Value = crate::web::post_json(ensembl.get_client(), &resource, &serde_json::json!({ "ids": batch })).await?;
+        if let Some(error) = json["error"].as_str() {
+            eprintln!("{{\"error\":\"{}\"}}", error);
+            return Err(Box::new(std::io::Error::new(std::io::ErrorKind::InvalidInput, error)));
+        }
+        response.sequences.extend(serde_json::from_value::<Vec<EnsemblSequence>>(json)?);
+    }
+
+    Ok(response)
+}
+
+/// The EnsEMBL genes a gene symbol, such as an HGNC symbol, resolves to.
+pub struct SymbolResolution {
+    pub symbol: String,
+    pub species: String,
+    /// Every gene identifier the symbol resolves to, the gene named by the symbol first.
+    pub ensembl_ids: Vec<String>,
+    /// The genes looked up, in the order of `ensembl_ids`.
+    pub genes: Vec<crate::gene::Gene>,
+    /// The sequences fetched from EnsEMBL, in the order of the genes they were requested by.
+    pub sequences: Vec<EnsemblSequence>,
+}
+
+impl SymbolResolution {
+    /// Whether the symbol resolves to more than one gene identifier.
+    pub fn is_ambiguous(&self) -> bool {
+        self.ensembl_ids.len() > 1
+    }
+
+    /// A JSON report of the identifiers an ambiguous symbol resolves to, or `None` if the symbol is unambiguous.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let resolution = utils::genome_browser::SymbolResolution {
+    ///     symbol: String::from("CYP2D6"),
+    ///     species: String::from("homo_sapiens"),
+    ///     ensembl_ids: vec![String::from("ENSG00000100197"), String::from("ENSG00000282966")],
+    ///     genes: Vec::new(),
+    ///     sequences: Vec::new(),
+    /// };
+    /// assert!(resolution.is_ambiguous());
+    /// assert_eq!(
+    ///     resolution.ambiguity_report().unwrap(),
+    ///     r#"{"ids":["ENSG00000100197","ENSG00000282966"],"species":"homo_sapiens","symbol":"CYP2D6","warning":"The symbol resolves to 2 EnsEMBL identifiers."}"#
+    /// );
+    /// ```
+    pub fn ambiguity_report(&self) -> Option<String> {
+        match self.is_ambiguous() {
+            true => Some(
+                serde_json::json!({
+                    "symbol": self.symbol,
+                    "species": self.species,
+                    "ids": self.ensembl_ids,
+                    "warning": format!("The symbol resolves to {} EnsEMBL identifiers.", self.ensembl_ids.len()),
+                })
+                .to_string(),
+            ),
+            false => None,
+        }
+    }
+}
+
+/// Resolve a gene symbol of the species of an EnsEMBL genome browser, such as `BRCA1` of `homo_sapiens`, to EnsEMBL genes.
+///
+/// The gene named by the symbol is found with `/lookup/symbol`, and genes the symbol is otherwise attached to,
+/// such as genes on alternative haplotypes or genes with the symbol as a synonym, with `/xrefs/symbol`.
+/// Every gene found is returned; a symbol that resolves to more than one gene is reported by `SymbolResolution::ambiguity_report`.
+///
+/// # Example
+///
+/// ```no_run
+/// let runtime = actix_web::rt::Runtime::new().unwrap();
+/// let resolution = runtime.block_on(async {
+///     let ensembl = utils::genome_browser::ensembl::EnsemblGenomeBrowser::new(awc::Client::default()).with_species("homo_sapiens");
+///     utils::genome_browser::ensembl_symbol_search(&ensembl, "TSPAN6", None).await.unwrap()
+/// });
+/// assert_eq!(resolution.ensembl_ids[0], "ENSG00000000003");
+/// ```
+pub async fn ensembl_symbol_search(
+    ensembl: &ensembl::EnsemblGenomeBrowser,
+    symbol: &str,
+    sequence_source: Option<&SequenceSource<'_>>
+) -> Result<SymbolResolution, Box<dyn std::error::Error>> {
+    let species: &str = ensembl.get_species();
+    let mut resolution: SymbolResolution = SymbolResolution {
+        symbol: String::from(symbol),
+        species: String::from(species),
+        ensembl_ids: Vec::new(),
+        genes: Vec::new(),
+        sequences: Vec::new(),
+    };
+
+    // The gene named by the symbol. EnsEMBL responds with an error if no gene is named by the symbol.
+    let resource: String = format!("{}/lookup/symbol/{species}/{symbol}", ensembl.get_base_url());
+    let json: serde_json::Value = ensembl_get_json(ensembl.get_client(), &resource).await?;
+    if let (None, Some(id)) = (json["error"].as_str(), json["id"].as_str()) {
+        resolution.ensembl_ids.push(String::from(id));
+    }
+
+    // The genes the symbol is a name or synonym of.
+    let resource: String = format!("{}/xrefs/symbol/{species}/{symbol}?object_type=gene", ensembl.get_base_url());
+    let json: serde_json::Value = ensembl_get_json(ensembl.get_client(), &resource).await?;
+    if let Some(error) = json["error"].as_str() {
+        eprintln!("{{\"symbol\":\"{}\", \"error\":\"{}\"}}", symbol, error);
+    }
+    for xref in json.as_array().map(|xrefs| &xrefs[..]).unwrap_or(&[]) {
+        if let Some(id) = xref["id"].as_str() {
+            if xref["type"].as_str().unwrap_or("gene") == "gene" && !resolution.ensembl_ids.iter().any(|ensembl_id| ensembl_id == id) {
+                resolution.ensembl_ids.push(String::from(id));
+            }
+        }
+    }
+
+    if resolution.ensembl_ids.is_empty() {
+        return Err(Box::new(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            format!("EnsEMBL could not resolve the symbol {symbol} of {species}."),
+        )));
+    }
+
+    // Look up every gene the symbol resolves to.
+    let batch_response: EnsemblBatchResponse = ensembl_batch_search(ensembl, &resolution.ensembl_ids, sequence_source).await?;
+    resolution.ensembl_ids.retain(|id| !batch_response.unresolved.contains(id));
+    resolution.genes.extend(batch_response.genes.into_iter().map(|(_, gene)| gene));
+    resolution.sequences = batch_response.sequences;
+
+    Ok(resolution)
+}
+
+/// Request JSON from EnsEMBL, returning the JSON error EnsEMBL responds to an unknown symbol with as the JSON.
+async fn ensembl_get_json(
+    client: &awc::Client,
+    resource: &str,
+) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+    let json: Result<serde_json::Value, Box<dyn std::error::Error>> = crate::web::get_json(client, resource).await;
+    match json {
+        Ok(json) => Ok(json),
+        // EnsEMBL responds to a symbol it does not know with an error status and a JSON error, returned as the JSON.
+        Err(error) => match error.downcast_ref::<crate::web::FetchError>() {
+            Some(fetch_error @ crate::web::FetchError::Status { body, .. }) if !fetch_error.is_transient() => {
+                Ok(serde_json::from_str(body).unwrap_or_default())
+            }
+            _ => Err(error),
+        },
+    }
+}
+
+pub struct EnsemblXrefResponse {
+    pub xrefs: Vec<crate::xref::ExternalReference>,
+    pub timestamp: std::time::SystemTime
+}
+
+/// Retrieve the cross-references of an EnsEMBL gene to external databases with `/xrefs/id`, including those of
+/// the gene's transcripts and translations, such as UniProt and RefSeq entries.
+///
+/// Only cross-references to `databases` are returned, or every cross-reference if `databases` is empty.
+/// A cross-reference shared by several transcripts or translations is returned once.
+///
+/// # Example
+///
+/// ```no_run
+/// use utils::xref::ExternalDatabase;
+///
+/// let runtime = actix_web::rt::Runtime::new().unwrap();
+/// let gene: utils::gene::Gene = runtime.block_on(async {
+///     let ensembl = utils::genome_browser::ensembl::EnsemblGenomeBrowser::new(awc::Client::default());
+///     let gene: utils::gene::Gene = utils::gene::Gene::builder().id("ENSG00000000003").build();
+///     let response = utils::genome_browser::ensembl_xrefs_search(&ensembl, gene.get_id(), &[ExternalDatabase::NcbiGene]).await.unwrap();
+///     gene.with_xrefs(response.xrefs)
+/// });
+/// assert_eq!(gene.get_xref_ids(&ExternalDatabase::NcbiGene), ["7105"]);
+/// ```
+pub async fn ensembl_xrefs_search(
+    ensembl: &ensembl::EnsemblGenomeBrowser,
+    ensembl_id: &str,
+    databases: &[crate::xref::ExternalDatabase]
+) -> Result<EnsemblXrefResponse, Box<dyn std::error::Error>> {
+    // A single database is filtered by EnsEMBL, several are filtered once the cross-references are retrieved.
+    let base_url: &str = ensembl.get_base_url();
+    let resource: String = match databases {
+        [database] => format!("{base_url}/xrefs/id/{ensembl_id}?all_levels=1;external_db={}", database.get_ensembl_name()),
+        _ => format!("{base_url}/xrefs/id/{ensembl_id}?all_levels=1"),
+    };
+
+    let json: serde_json::Value = match crate::web::get_json(ensembl.get_client(), &resource).await {
+        Ok(json) => json,
+        Err(error) => {
+            eprintln!("{{\"id\":\"{}\", \"error\":\"{}\"}}", ensembl_id, error);
+            return Err(error);
+        }
+    };
+    let timestamp: std::time::SystemTime = std::time::SystemTime::now();
+    if let Some(error) = json["error"].as_str() {
+        eprintln!("{{\"id\":\"{}\", \"error\":\"{}\"}}", ensembl_id, error);
+        return Err(Box::new(std::io::Error::new(std::io::ErrorKind::NotFound, error)));
+    }
+
+    let mut xrefs: Vec<crate::xref::ExternalReference> = Vec::new();
+    for xref in serde_json::from_value::<Vec<crate::xref::ExternalReference>>(json)? {
+        let selected: bool = databases.is_empty() || databases.contains(xref.get_database());
+        let duplicate: bool = xrefs.iter().any(|other| {
+            other.get_database() == xref.get_database() && other.get_primary_id() == xref.get_primary_id()
+        });
+        if selected && !duplicate {
+            xrefs.push(xref);
+        }
+    }
+
+    Ok(EnsemblXrefResponse { xrefs, timestamp })
+}
+
+/// Fetch the DNA of a looked up gene's region from a genome browser, such as the University of California Santa Cruz Genome Browser.
+///
+/// The DNA of an assembly the genome browser does not serve is empty; a gene without a start and end, a request that fails,
+/// a response that is not JSON with DNA, or DNA that is not IUPAC nucleotide codes, is an error.
+async fn region_dna(
+    sequence_browser: &dyn GenomeBrowser,
+    lookup: &EnsemblLookup
+) -> Result<crate::seq::nucleotide::DnaSequence, Box<dyn std::error::Error>> {
+    let dna: Result<crate::seq::nucleotide::DnaSequence, Box<dyn std::error::Error>> = match (lookup.start, lookup.end) {
+        (Some(start), Some(end)) => sequence_browser
+            .region_sequence(lookup.get_assembly_name(), lookup.get_seq_region_name(), start, end)
+            .await
+            .and_then(|dna| Ok(dna.parse::<crate::seq::nucleotide::DnaSequence>()?)),
+        _ => Err(Box::new(std::io::Error::new(std::io::ErrorKind::InvalidData, "the gene's start and end are unknown"))),
+    };
+    match dna {
+        Ok(dna) => Ok(dna),
+        Err(error) => {
+            eprintln!(
+                "{{\"assembly_name\":\"{}\", \"start\":\"{}\", \"end\":\"{}\", \"error\":\"{}\"}}",
+                lookup.get_assembly_name(), lookup.get_start(), lookup.get_end(), error
+            );
+            match error.is::<assembly::UnsupportedAssemblyError>() {
+                true => Ok(crate::seq::nucleotide::DnaSequence::new()),
+                false => Err(error),
+            }
+        }
+    }
+}
+
+/// Look up an EnsEMBL identifier through the HTML view of `/lookup/id`, parsing its pre-formatted text as YAML.
+async fn ensembl_html_lookup(
+    client: &awc::Client,
+    ensembl_id: &str,
+    resource: &str,
+) -> Result<EnsemblLookup, Box<dyn std::error::Error>> {
+    let html = match crate::web::get_html_body(client, resource).await {
+        Ok(html) => html,
+        Err(error) => {
+            eprintln!("{{\"id\":\"{}\", \"error\":\"{}\"}}", ensembl_id, error);
+            return Err(error);
+        }
+    };
+
+    let parsed = crate::html::parse_pre_elements(&html[..]);
+
+    let ensembl_yaml: Result<serde_yaml::Value, serde_yaml::Error> =
+        serde_yaml::from_str(&parsed[..]);
+
+    match ensembl_yaml {
+        Ok(yaml) => Ok(EnsemblLookup::from_yaml(&yaml)),
         Err(error) => {
             eprintln!("{{\"id\":\"{}\", \"error\":\"{}\"}}", ensembl_id, error);
             Err(Box::new(error))
@@ -174,6 +959,10 @@ pub async fn ensembl_search(
 }
 
 /// Search the University of California Santa Cruz Genome Browser for DNA strings.
+///
+/// The UCSC Genome Browser's JSON response is returned as it is. EnsEMBL assembly and chromosome names are translated to
+/// the UCSC Genome Browser's (see `assembly`); an assembly without a UCSC Genome Browser genome is an
+/// `assembly::UnsupportedAssemblyError`. Starts are 0-based, as in the UCSC Genome Browser.
 /// 
 /// # Example
 /// 
@@ -181,53 +970,47 @@ pub async fn ensembl_search(
 /// let runtime = actix_web::rt::Runtime::new().unwrap();
 /// assert!(
 /// runtime.block_on(async {
-///     let client: awc::Client = awc::Client::default();
+///     let ucsc = utils::genome_browser::ucsc::UcscGenomeBrowser::new(awc::Client::default());
 ///     let assembly_name = "hg38";
 ///     let start = "1";
 ///     let end = "2";
 ///     let chromosome = "X";
-///     let last_request_made: Option<std::time::SystemTime> = None;
-///     let ucsc_genome_browser_response: String = utils::genome_browser::ucsc_genome_browser_search(&client,
+///     let ucsc_genome_browser_response: String = utils::genome_browser::ucsc_genome_browser_search(&ucsc,
 ///                                                                                assembly_name,
 ///                                                                                start,
 ///                                                                                end,
-///                                                                                chromosome,
-///                                                                                last_request_made).await.unwrap();
+///                                                                                chromosome).await.unwrap();
 ///     let json: serde_json::Value = serde_json::from_str(&ucsc_genome_browser_response).unwrap();
 ///     json["start"].as_u64().unwrap_or(0 as u64)
 ///  }) == 1);
 /// ```
 pub async fn ucsc_genome_browser_search(
-    client: &awc::Client,
+    ucsc: &ucsc::UcscGenomeBrowser,
     assembly_name: &str,
     start: &str,
     end: &str,
-    chromosome: &str,
-    last_request_made: Option<std::time::SystemTime>
+    chromosome: &str
 ) -> Result<String, Box<dyn std::error::Error>> {
 
-    let genome: &str = match assembly_name {
-        "GRCh38" => "hg38",
-        "GRCh37" => "hg19",
-        genome => genome
+    let genome: &str = match assembly::ucsc_genome(assembly_name) {
+        Ok(genome) => genome,
+        Err(error) => {
+            eprintln!("{{\"assembly_name\":\"{}\", \"error\":\"{}\"}}", assembly_name, error);
+            return Err(Box::new(error));
+        }
     };
+    let chromosome: String = assembly::ucsc_chromosome(chromosome);
 
-    let resource = format!("{UCSC_GENOME_BASE_URL}/getData/sequence?genome={genome};chrom=chr{chromosome};start={start};end={end};");
-
-    // A maximum of one request can be made to the University of California Santa Cruz Genome Browser approximately every 15_000 milliseconds (15 seconds).
-    // Wait 15_000 milliseconds (15 seconds) if the time the last request is made is supplied.
-    if last_request_made.is_some() {
-        crate::time::sleep_until_time_elapsed(last_request_made.unwrap(), 15_000 as u64);
-    }
+    let resource = format!("{}/getData/sequence?genome={genome};chrom={chromosome};start={start};end={end};", ucsc.get_base_url());
 
-    let json = match crate::web::get_html_body(client, &resource[..]).await {
+    let json = match crate::web::get_html_body(ucsc.get_client(), &resource[..]).await {
         Ok(html) => html,
         Err(error) => {
             eprintln!(
                 "{{\"url\": \"{}\", \"assembly_name\":\"{}\", \"start\": \"{}, \"end\":{}, \"error\":\"{}\"}}",
                 resource, assembly_name, start, end, error
             );
-            String::from("")
+            return Err(error);
         }
     };
 
@@ -0,0 +1,139 @@
+//! MIT license.
+
+/// An EnsEMBL assembly and the University of California Santa Cruz Genome Browser's genome of it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UcscAssembly {
+    /// The assembly's name in EnsEMBL, or the prefix of its names when EnsEMBL versions its releases (e.g. BDGP6).
+    pub ensembl_name: &'static str,
+    pub ucsc_genome: &'static str,
+    pub species: &'static str,
+}
+
+/// The assemblies of model organisms served by the UCSC Genome Browser.
+pub const UCSC_ASSEMBLIES: [UcscAssembly; 17] = [
+    UcscAssembly { ensembl_name: "GRCh38", ucsc_genome: "hg38", species: "homo_sapiens" },
+    UcscAssembly { ensembl_name: "GRCh37", ucsc_genome: "hg19", species: "homo_sapiens" },
+    UcscAssembly { ensembl_name: "GRCm39", ucsc_genome: "mm39", species: "mus_musculus" },
+    UcscAssembly { ensembl_name: "GRCm38", ucsc_genome: "mm10", species: "mus_musculus" },
+    UcscAssembly { ensembl_name: "mRatBN7.2", ucsc_genome: "rn7", species: "rattus_norvegicus" },
+    UcscAssembly { ensembl_name: "Rnor_6.0", ucsc_genome: "rn6", species: "rattus_norvegicus" },
+    UcscAssembly { ensembl_name: "GRCz11", ucsc_genome: "danRer11", species: "danio_rerio" },
+    UcscAssembly { ensembl_name: "GRCz10", ucsc_genome: "danRer10", species: "danio_rerio" },
+    UcscAssembly { ensembl_name: "BDGP6", ucsc_genome: "dm6", species: "drosophila_melanogaster" },
+    UcscAssembly { ensembl_name: "WBcel235", ucsc_genome: "ce11", species: "caenorhabditis_elegans" },
+    UcscAssembly { ensembl_name: "R64-1-1", ucsc_genome: "sacCer3", species: "saccharomyces_cerevisiae" },
+    UcscAssembly { ensembl_name: "GRCg6a", ucsc_genome: "galGal6", species: "gallus_gallus" },
+    UcscAssembly { ensembl_name: "Xenopus_tropicalis_v9.1", ucsc_genome: "xenTro9", species: "xenopus_tropicalis" },
+    UcscAssembly { ensembl_name: "Sscrofa11.1", ucsc_genome: "susScr11", species: "sus_scrofa" },
+    UcscAssembly { ensembl_name: "ARS-UCD1.2", ucsc_genome: "bosTau9", species: "bos_taurus" },
+    UcscAssembly { ensembl_name: "CanFam3.1", ucsc_genome: "canFam3", species: "canis_lupus_familiaris" },
+    UcscAssembly { ensembl_name: "Mmul_10", ucsc_genome: "rheMac10", species: "macaca_mulatta" },
+];
+
+/// An assembly the UCSC Genome Browser does not serve, or that is not in `UCSC_ASSEMBLIES`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnsupportedAssemblyError {
+    assembly_name: String,
+}
+
+impl UnsupportedAssemblyError {
+    pub fn get_assembly_name(&self) -> &str {
+        &self.assembly_name
+    }
+}
+
+impl std::fmt::Display for UnsupportedAssemblyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let supported: Vec<&str> = UCSC_ASSEMBLIES.iter().map(|assembly| assembly.ensembl_name).collect();
+        write!(
+            f,
+            "the assembly {} has no UCSC Genome Browser genome; supported assemblies are {}",
+            self.assembly_name,
+            supported.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for UnsupportedAssemblyError {}
+
+/// The UCSC Genome Browser's genome of an EnsEMBL assembly. UCSC genome names are returned as they are.
+///
+/// # Example
+///
+/// ```
+/// use utils::genome_browser::assembly::ucsc_genome;
+///
+/// assert_eq!(ucsc_genome("GRCm39"), Ok("mm39"));
+/// assert_eq!(ucsc_genome("BDGP6.46"), Ok("dm6"));
+/// assert_eq!(ucsc_genome("danRer11"), Ok("danRer11"));
+/// assert!(ucsc_genome("TAIR10").unwrap_err().to_string().starts_with("the assembly TAIR10 has no UCSC Genome Browser genome"));
+/// ```
+pub fn ucsc_genome(assembly_name: &str) -> Result<&'static str, UnsupportedAssemblyError> {
+    UCSC_ASSEMBLIES
+        .iter()
+        .find(|assembly| {
+            assembly.ensembl_name == assembly_name
+                || assembly.ucsc_genome == assembly_name
+                || assembly_name.strip_prefix(assembly.ensembl_name).is_some_and(|release| release.starts_with('.'))
+        })
+        .map(|assembly| assembly.ucsc_genome)
+        .ok_or_else(|| UnsupportedAssemblyError { assembly_name: String::from(assembly_name) })
+}
+
+/// The UCSC Genome Browser's name of an EnsEMBL chromosome, or sequence region.
+///
+/// Chromosomes are prefixed with `chr`, and mitochondrial DNA is `chrM`. Unplaced scaffolds, named by their
+/// GenBank accession in EnsEMBL (e.g. KI270742.1), are named as UCSC names unplaced scaffolds (e.g. chrUn_KI270742v1);
+/// scaffolds UCSC places on a chromosome are found with `ucsc_scaffold` instead.
+///
+/// # Example
+///
+/// ```
+/// use utils::genome_browser::assembly::ucsc_chromosome;
+///
+/// assert_eq!(ucsc_chromosome("X"), "chrX");
+/// assert_eq!(ucsc_chromosome("MT"), "chrM");
+/// assert_eq!(ucsc_chromosome("Mito"), "chrM");
+/// assert_eq!(ucsc_chromosome("KI270742.1"), "chrUn_KI270742v1");
+/// assert_eq!(ucsc_chromosome("chr2L"), "chr2L");
+/// ```
+pub fn ucsc_chromosome(chromosome: &str) -> String {
+    match chromosome {
+        "MT" | "Mito" | "MtDNA" | "mitochondrion_genome" => String::from("chrM"),
+        chromosome if chromosome.starts_with("chr") => String::from(chromosome),
+        chromosome => match scaffold_accession(chromosome) {
+            Some(accession) => format!("chrUn_{accession}"),
+            None => format!("chr{chromosome}"),
+        },
+    }
+}
+
+/// Find the UCSC Genome Browser's name of an EnsEMBL scaffold among a genome's sequence names, such as
+/// `chr1_KI270706v1_random` for KI270706.1, or `None` if the chromosome is not a scaffold or is not among the names.
+///
+/// # Example
+///
+/// ```
+/// let names = ["chr1", "chr1_KI270706v1_random", "chrUn_KI270742v1"];
+/// assert_eq!(utils::genome_browser::assembly::ucsc_scaffold("KI270706.1", &names), Some("chr1_KI270706v1_random"));
+/// assert_eq!(utils::genome_browser::assembly::ucsc_scaffold("1", &names), None);
+/// ```
+pub fn ucsc_scaffold<'a, S: AsRef<str>>(chromosome: &str, names: &'a [S]) -> Option<&'a str> {
+    let accession: String = scaffold_accession(chromosome)?;
+    names
+        .iter()
+        .map(|name| name.as_ref())
+        .find(|name| name.split('_').any(|part| part == accession))
+}
+
+/// The UCSC Genome Browser's form of a GenBank accession naming a scaffold, such as KI270742v1 for KI270742.1.
+fn scaffold_accession(chromosome: &str) -> Option<String> {
+    let (accession, version) = chromosome.split_once('.')?;
+    let letters: usize = accession.chars().take_while(|character| character.is_ascii_uppercase()).count();
+    let is_accession: bool = letters > 0
+        && accession.len() > letters
+        && accession[letters..].chars().all(|character| character.is_ascii_digit())
+        && !version.is_empty()
+        && version.chars().all(|character| character.is_ascii_digit());
+    is_accession.then(|| format!("{accession}v{version}"))
+}
@@ -0,0 +1,99 @@
+//! MIT license.
+
+/// The EnsEMBL REST API, or a mirror of it, as a genome browser of one species.
+///
+/// # Example
+///
+/// ```no_run
+/// use utils::genome_browser::GenomeBrowser;
+///
+/// let browser = utils::genome_browser::ensembl::EnsemblGenomeBrowser::new(awc::Client::default())
+///     .with_base_url(utils::genome_browser::ENSEMBL_GRCH37_BASE_URL);
+/// let runtime = actix_web::rt::Runtime::new().unwrap();
+/// let assemblies: Vec<String> = runtime.block_on(browser.assemblies()).unwrap();
+/// assert_eq!(assemblies, ["GRCh37"]);
+/// ```
+pub struct EnsemblGenomeBrowser {
+    client: awc::Client,
+    base_url: String,
+    species: String,
+}
+
+impl EnsemblGenomeBrowser {
+    /// A genome browser of `homo_sapiens` at the EnsEMBL REST API.
+    pub fn new(client: awc::Client) -> EnsemblGenomeBrowser {
+        EnsemblGenomeBrowser {
+            client,
+            base_url: String::from(super::ENSEMBL_BASE_URL),
+            species: String::from("homo_sapiens"),
+        }
+    }
+
+    /// Use a mirror of the EnsEMBL REST API, such as `ENSEMBL_GRCH37_BASE_URL`.
+    pub fn with_base_url(mut self, base_url: &str) -> EnsemblGenomeBrowser {
+        self.base_url = String::from(base_url.trim_end_matches('/'));
+        self
+    }
+
+    /// Fetch the sequences of a species other than `homo_sapiens`, such as `mus_musculus`.
+    pub fn with_species(mut self, species: &str) -> EnsemblGenomeBrowser {
+        self.species = String::from(species);
+        self
+    }
+
+    pub fn get_client(&self) -> &awc::Client {
+        &self.client
+    }
+
+    pub fn get_base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn get_species(&self) -> &str {
+        &self.species
+    }
+
+    /// Request JSON from the REST API, counted in `quota::global()`, returning EnsEMBL's error message as an error.
+    async fn get_json(&self, resource: &str) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+        let json: serde_json::Value = crate::web::get_json(&self.client, resource).await?;
+        match json["error"].as_str() {
+            Some(error) => {
+                eprintln!("{{\"url\": \"{}\", \"error\": \"{}\"}}", resource, error);
+                Err(Box::new(std::io::Error::new(std::io::ErrorKind::NotFound, error)))
+            }
+            None => Ok(json),
+        }
+    }
+}
+
+impl super::GenomeBrowser for EnsemblGenomeBrowser {
+    fn lookup_gene<'a>(&'a self, id: &'a str) -> super::GenomeBrowserFuture<'a, crate::gene::Gene> {
+        Box::pin(async move {
+            let json: serde_json::Value = self.get_json(&format!("{}/lookup/id/{id}", self.base_url)).await?;
+            let lookup: super::EnsemblLookup = serde_json::from_value(json)?;
+            Ok(lookup.to_gene(crate::seq::nucleotide::DnaSequence::new()))
+        })
+    }
+
+    fn region_sequence<'a>(&'a self, assembly_name: &'a str, chromosome: &'a str, start: u64, end: u64) -> super::GenomeBrowserFuture<'a, String> {
+        Box::pin(async move {
+            let resource: String = format!(
+                "{}/sequence/region/{}/{chromosome}:{start}..{end}:1?coord_system_version={assembly_name}",
+                self.base_url, self.species
+            );
+            let json: serde_json::Value = self.get_json(&resource).await?;
+            match json["seq"].as_str() {
+                Some(seq) => Ok(String::from(seq)),
+                None => Err(Box::new(std::io::Error::new(std::io::ErrorKind::InvalidData, "the response holds no sequence")) as Box<dyn std::error::Error>),
+            }
+        })
+    }
+
+    fn assemblies(&self) -> super::GenomeBrowserFuture<'_, Vec<String>> {
+        Box::pin(async move {
+            // EnsEMBL serves a single assembly of each species.
+            let json: serde_json::Value = self.get_json(&format!("{}/info/assembly/{}", self.base_url, self.species)).await?;
+            Ok(json["assembly_name"].as_str().map(String::from).into_iter().collect())
+        })
+    }
+}
@@ -0,0 +1,85 @@
+//! MIT license.
+
+/// Local files as a genome browser of one assembly: genes from a CSV file written by the `ensembl_search` program,
+/// and chromosome sequences from FASTA files, such as EnsEMBL's `dna_sm.toplevel.fa.gz`.
+///
+/// Chromosomes are found by the identifier of their FASTA record, with or without a `chr` prefix.
+pub struct LocalGenomeBrowser {
+    assembly_name: String,
+    genes: Vec<crate::gene::Gene>,
+    sequences: std::collections::HashMap<String, String>,
+}
+
+impl LocalGenomeBrowser {
+    /// A genome browser of an assembly, without genes or sequences.
+    pub fn new(assembly_name: &str) -> LocalGenomeBrowser {
+        LocalGenomeBrowser {
+            assembly_name: String::from(assembly_name),
+            genes: Vec::new(),
+            sequences: std::collections::HashMap::new(),
+        }
+    }
+
+    /// Read genes from a CSV file written by the `ensembl_search` program.
+    pub fn with_genes(mut self, path: &std::path::Path) -> Result<LocalGenomeBrowser, Box<dyn std::error::Error>> {
+        self.genes.extend(crate::gene::Gene::read_csv(path)?);
+        Ok(self)
+    }
+
+    /// Read chromosome sequences from a FASTA file, which may be compressed with gzip.
+    pub fn with_fasta(mut self, path: &std::path::Path) -> Result<LocalGenomeBrowser, Box<dyn std::error::Error>> {
+        for record in crate::seq::io::FastaReader::from_path(path)? {
+            let record: crate::seq::io::FastaRecord = record?;
+            self.sequences.insert(String::from(record.get_id()), String::from(record.get_sequence()));
+        }
+        Ok(self)
+    }
+
+    pub fn get_assembly_name(&self) -> &str {
+        &self.assembly_name
+    }
+
+    fn get_chromosome(&self, chromosome: &str) -> Option<&String> {
+        self.sequences
+            .get(chromosome)
+            .or_else(|| self.sequences.get(chromosome.trim_start_matches("chr")))
+            .or_else(|| self.sequences.get(&format!("chr{chromosome}")))
+    }
+
+    fn region(&self, assembly_name: &str, chromosome: &str, start: u64, end: u64) -> Result<String, Box<dyn std::error::Error>> {
+        if assembly_name != self.assembly_name {
+            return Err(Box::new(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("the assembly {assembly_name} is not {}", self.assembly_name),
+            )));
+        }
+        let sequence: &String = self.get_chromosome(chromosome).ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::NotFound, format!("the chromosome {chromosome} is not in {assembly_name}"))
+        })?;
+        match (start.checked_sub(1), end) {
+            (Some(offset), end) if offset < end && end as usize <= sequence.len() => Ok(String::from(&sequence[offset as usize..end as usize])),
+            _ => Err(Box::new(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("{chromosome}:{start}-{end} is outside of chromosome {chromosome} of length {}", sequence.len()),
+            ))),
+        }
+    }
+}
+
+impl super::GenomeBrowser for LocalGenomeBrowser {
+    fn lookup_gene<'a>(&'a self, id: &'a str) -> super::GenomeBrowserFuture<'a, crate::gene::Gene> {
+        let gene: Result<crate::gene::Gene, Box<dyn std::error::Error>> = match self.genes.iter().find(|gene| gene.get_id() == id) {
+            Some(gene) => Ok(gene.clone()),
+            None => Err(Box::new(std::io::Error::new(std::io::ErrorKind::NotFound, format!("the gene {id} is not in the genes read")))),
+        };
+        Box::pin(std::future::ready(gene))
+    }
+
+    fn region_sequence<'a>(&'a self, assembly_name: &'a str, chromosome: &'a str, start: u64, end: u64) -> super::GenomeBrowserFuture<'a, String> {
+        Box::pin(std::future::ready(self.region(assembly_name, chromosome, start, end)))
+    }
+
+    fn assemblies(&self) -> super::GenomeBrowserFuture<'_, Vec<String>> {
+        Box::pin(std::future::ready(Ok(vec![self.assembly_name.clone()])))
+    }
+}
@@ -0,0 +1,123 @@
+//! MIT license.
+
+/// The University of California Santa Cruz Genome Browser's REST API, or a mirror of it, as a genome browser.
+///
+/// The UCSC Genome Browser serves sequences, but does not look up genes by EnsEMBL identifier.
+/// Its assemblies are listed by its own genome names (e.g. hg38), which are accepted as well as EnsEMBL's assembly names.
+/// EnsEMBL chromosome names are translated, and scaffolds are found among the genome's sequence names, listed once per genome.
+///
+/// # Example
+///
+/// ```no_run
+/// use utils::genome_browser::GenomeBrowser;
+///
+/// let browser = utils::genome_browser::ucsc::UcscGenomeBrowser::new(awc::Client::default());
+/// let runtime = actix_web::rt::Runtime::new().unwrap();
+/// let dna: String = runtime.block_on(browser.region_sequence("GRCh38", "X", 100627109, 100627118)).unwrap();
+/// assert_eq!(dna.len(), 10);
+/// ```
+pub struct UcscGenomeBrowser {
+    client: awc::Client,
+    base_url: String,
+    /// The sequence names of each genome listed, to find the names of scaffolds.
+    chromosomes: std::cell::RefCell<std::collections::HashMap<String, Vec<String>>>,
+}
+
+impl UcscGenomeBrowser {
+    /// A genome browser at the UCSC Genome Browser's REST API.
+    pub fn new(client: awc::Client) -> UcscGenomeBrowser {
+        UcscGenomeBrowser {
+            client,
+            base_url: String::from(super::UCSC_GENOME_BASE_URL),
+            chromosomes: std::cell::RefCell::new(std::collections::HashMap::new()),
+        }
+    }
+
+    /// Use a mirror of the UCSC Genome Browser's REST API.
+    pub fn with_base_url(mut self, base_url: &str) -> UcscGenomeBrowser {
+        self.base_url = String::from(base_url.trim_end_matches('/'));
+        self
+    }
+
+    pub fn get_client(&self) -> &awc::Client {
+        &self.client
+    }
+
+    pub fn get_base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// The UCSC Genome Browser's name of an EnsEMBL chromosome of a genome, listing the genome's sequence names to find scaffolds.
+    async fn chromosome(&self, genome: &str, chromosome: &str) -> Result<String, Box<dyn std::error::Error>> {
+        let name: String = super::assembly::ucsc_chromosome(chromosome);
+        if !name.starts_with("chrUn_") || chromosome.starts_with("chr") {
+            return Ok(name);
+        }
+
+        if !self.chromosomes.borrow().contains_key(genome) {
+            let json: serde_json::Value = self.get_json(&format!("{}/list/chromosomes?genome={genome}", self.base_url)).await?;
+            let names: Vec<String> = json["chromosomes"]
+                .as_object()
+                .map(|chromosomes| chromosomes.keys().cloned().collect())
+                .unwrap_or_default();
+            self.chromosomes.borrow_mut().insert(String::from(genome), names);
+        }
+
+        let chromosomes = self.chromosomes.borrow();
+        Ok(String::from(super::assembly::ucsc_scaffold(chromosome, &chromosomes[genome]).unwrap_or(&name)))
+    }
+
+    /// Request JSON from the REST API, counted in `quota::global()`, returning the UCSC Genome Browser's error message as an error.
+    async fn get_json(&self, resource: &str) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+        let json: serde_json::Value = crate::web::get_json(&self.client, resource).await?;
+        match json["error"].as_str() {
+            Some(error) => {
+                eprintln!("{{\"url\": \"{}\", \"error\": \"{}\"}}", resource, error);
+                Err(Box::new(std::io::Error::new(std::io::ErrorKind::NotFound, error)))
+            }
+            None => Ok(json),
+        }
+    }
+}
+
+impl super::GenomeBrowser for UcscGenomeBrowser {
+    fn lookup_gene<'a>(&'a self, id: &'a str) -> super::GenomeBrowserFuture<'a, crate::gene::Gene> {
+        Box::pin(async move {
+            Err(Box::new(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                format!("the UCSC Genome Browser does not look up genes by identifier ({id})"),
+            )) as Box<dyn std::error::Error>)
+        })
+    }
+
+    fn region_sequence<'a>(&'a self, assembly_name: &'a str, chromosome: &'a str, start: u64, end: u64) -> super::GenomeBrowserFuture<'a, String> {
+        Box::pin(async move {
+            let genome: &str = super::assembly::ucsc_genome(assembly_name)?;
+            let chromosome: String = self.chromosome(genome, chromosome).await?;
+
+            // The UCSC Genome Browser's starts are 0-based, whereas EnsEMBL's starts are 1-based.
+            let resource: String = format!(
+                "{}/getData/sequence?genome={genome};chrom={chromosome};start={};end={end}",
+                self.base_url,
+                start.saturating_sub(1)
+            );
+            let json: serde_json::Value = self.get_json(&resource).await?;
+            match json["dna"].as_str() {
+                Some(dna) => Ok(String::from(dna)),
+                None => Err(Box::new(std::io::Error::new(std::io::ErrorKind::InvalidData, "the response holds no DNA")) as Box<dyn std::error::Error>),
+            }
+        })
+    }
+
+    fn assemblies(&self) -> super::GenomeBrowserFuture<'_, Vec<String>> {
+        Box::pin(async move {
+            let json: serde_json::Value = self.get_json(&format!("{}/list/ucscGenomes", self.base_url)).await?;
+            let mut genomes: Vec<String> = json["ucscGenomes"]
+                .as_object()
+                .map(|genomes| genomes.keys().cloned().collect())
+                .unwrap_or_default();
+            genomes.sort();
+            Ok(genomes)
+        })
+    }
+}
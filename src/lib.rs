@@ -1,9 +1,14 @@
 //! MIT license.
 
+pub mod cache;
 pub mod flat_file;
 pub mod gene;
 pub mod genome_browser;
 pub mod html;
+pub mod quota;
+pub mod rate_limit;
 pub mod seq;
 pub mod time;
-pub mod web;
\ No newline at end of file
+pub mod transcript;
+pub mod web;
+pub mod xref;
\ No newline at end of file
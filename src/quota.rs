@@ -0,0 +1,186 @@
+//! MIT license.
+
+/// The EnsEMBL REST API, counted against `ENSEMBL_DAILY_LIMIT`.
+pub const ENSEMBL: &str = "ensembl";
+/// The University of California Santa Cruz Genome Browser's REST API, which has no daily limit; its requests are counted alone.
+pub const UCSC: &str = "ucsc";
+
+/// A maximum of 5,000 requests can be made to EnsEMBL per day.
+pub const ENSEMBL_DAILY_LIMIT: u32 = 5_000;
+
+/// The environment variable naming the ledger file of `global()`.
+pub const LEDGER_PATH_VARIABLE: &str = "UTILS_QUOTA_LEDGER";
+
+/// A request refused because the daily limit of its service is used up.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QuotaExceededError {
+    service: String,
+    limit: u32,
+    date: String,
+}
+
+impl QuotaExceededError {
+    pub fn get_service(&self) -> &str {
+        &self.service
+    }
+
+    pub fn get_limit(&self) -> u32 {
+        self.limit
+    }
+
+    pub fn get_date(&self) -> &str {
+        &self.date
+    }
+}
+
+impl std::fmt::Display for QuotaExceededError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "The {} maximum number of requests ({}) of {} (UTC) has been exceeded.", self.service, self.limit, self.date)
+    }
+}
+
+impl std::error::Error for QuotaExceededError {}
+
+/// The requests of one day, by service.
+#[derive(Debug, Default, serde::Deserialize, serde::Serialize)]
+struct Ledger {
+    date: String,
+    requests: std::collections::BTreeMap<String, u32>,
+}
+
+/// The number of requests made to each service on the current calendar day (UTC), kept in a JSON file so that every run,
+/// and every process, on the day counts against the same daily limits.
+///
+/// The file is locked while it is read and updated. Counts of previous days are discarded.
+///
+/// # Example
+///
+/// ```
+/// use utils::quota::{QuotaLedger, ENSEMBL};
+///
+/// let directory = tempfile::tempdir().unwrap();
+/// let ledger: QuotaLedger = QuotaLedger::new(&directory.path().join("quota.json"));
+/// assert_eq!(ledger.used(ENSEMBL).unwrap(), 0);
+///
+/// assert_eq!(ledger.reserve(ENSEMBL, Some(2)).unwrap(), 1);
+/// assert_eq!(ledger.reserve(ENSEMBL, Some(2)).unwrap(), 2);
+/// assert!(ledger.reserve(ENSEMBL, Some(2)).is_err());
+///
+/// // Another run on the same day reads the same counts.
+/// let ledger: QuotaLedger = QuotaLedger::new(&directory.path().join("quota.json"));
+/// assert_eq!(ledger.remaining(ENSEMBL, 2).unwrap(), 0);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QuotaLedger {
+    path: std::path::PathBuf,
+}
+
+impl QuotaLedger {
+    /// A ledger kept in the file at `path`, which is created once a request is counted.
+    pub fn new(path: &std::path::Path) -> QuotaLedger {
+        QuotaLedger { path: path.to_path_buf() }
+    }
+
+    pub fn get_path(&self) -> &std::path::Path {
+        &self.path
+    }
+
+    /// The number of requests made to a service today.
+    pub fn used(&self, service: &str) -> Result<u32, Box<dyn std::error::Error>> {
+        if !self.path.exists() {
+            return Ok(0);
+        }
+        let file: std::fs::File = std::fs::File::open(&self.path)?;
+        file.lock_shared()?;
+        let ledger: Ledger = read_ledger(&file)?;
+        Ok(ledger.requests.get(service).copied().unwrap_or(0))
+    }
+
+    /// The number of requests that may still be made to a service today, of a daily limit.
+    pub fn remaining(&self, service: &str, limit: u32) -> Result<u32, Box<dyn std::error::Error>> {
+        Ok(limit.saturating_sub(self.used(service)?))
+    }
+
+    /// Count a request to a service, returning the number of requests made today including it, or a `QuotaExceededError`
+    /// if the daily limit, if any, is used up.
+    pub fn reserve(&self, service: &str, limit: Option<u32>) -> Result<u32, Box<dyn std::error::Error>> {
+        use std::io::{Seek, Write};
+
+        if let Some(directory) = self.path.parent().filter(|directory| !directory.as_os_str().is_empty()) {
+            std::fs::create_dir_all(directory)?;
+        }
+        let mut file: std::fs::File = std::fs::OpenOptions::new().read(true).write(true).create(true).truncate(false).open(&self.path)?;
+        file.lock()?;
+
+        let mut ledger: Ledger = read_ledger(&file)?;
+        let used: u32 = ledger.requests.get(service).copied().unwrap_or(0);
+        if let Some(limit) = limit.filter(|limit| used >= *limit) {
+            return Err(Box::new(QuotaExceededError { service: String::from(service), limit, date: ledger.date }));
+        }
+        ledger.requests.insert(String::from(service), used + 1);
+
+        file.set_len(0)?;
+        file.rewind()?;
+        file.write_all(serde_json::to_string(&ledger)?.as_bytes())?;
+        Ok(used + 1)
+    }
+}
+
+/// Read a ledger file, starting the current day's ledger if the file is empty or is of a previous day.
+fn read_ledger(mut file: &std::fs::File) -> Result<Ledger, Box<dyn std::error::Error>> {
+    use std::io::Read;
+
+    let mut contents: String = String::new();
+    file.read_to_string(&mut contents)?;
+    let today: String = crate::time::utc_date(std::time::SystemTime::now());
+    match contents.trim().is_empty() {
+        true => Ok(Ledger { date: today, ..Ledger::default() }),
+        false => {
+            let ledger: Ledger = serde_json::from_str(&contents)?;
+            match ledger.date == today {
+                true => Ok(ledger),
+                false => Ok(Ledger { date: today, ..Ledger::default() }),
+            }
+        }
+    }
+}
+
+/// The service, and daily limit, of the requests to a URL, if requests to its host are counted.
+///
+/// # Example
+///
+/// ```
+/// use utils::quota::{service, ENSEMBL, ENSEMBL_DAILY_LIMIT, UCSC};
+///
+/// assert_eq!(service("https://rest.ensembl.org/lookup/id/ENSG00000155542"), Some((ENSEMBL, Some(ENSEMBL_DAILY_LIMIT))));
+/// assert_eq!(service("https://api.genome.ucsc.edu/list/ucscGenomes"), Some((UCSC, None)));
+/// assert_eq!(service("https://rest.kegg.jp/get/hsa:TSPAN6"), None);
+/// ```
+pub fn service(url: &str) -> Option<(&'static str, Option<u32>)> {
+    let uri: awc::http::Uri = url.parse().ok()?;
+    match uri.host()? {
+        "rest.ensembl.org" | "grch37.rest.ensembl.org" => Some((ENSEMBL, Some(ENSEMBL_DAILY_LIMIT))),
+        "api.genome.ucsc.edu" => Some((UCSC, None)),
+        _ => None,
+    }
+}
+
+/// The ledger file of `global()`: the file named by the `UTILS_QUOTA_LEDGER` environment variable, or `utils/quota.json`
+/// in the user's data directory (`$XDG_DATA_HOME`, or `~/.local/share`).
+pub fn default_path() -> std::path::PathBuf {
+    if let Some(path) = std::env::var_os(LEDGER_PATH_VARIABLE).filter(|path| !path.is_empty()) {
+        return std::path::PathBuf::from(path);
+    }
+    let data_directory: std::path::PathBuf = match (std::env::var_os("XDG_DATA_HOME"), std::env::var_os("HOME")) {
+        (Some(data_home), _) if !data_home.is_empty() => std::path::PathBuf::from(data_home),
+        (_, Some(home)) if !home.is_empty() => std::path::Path::new(&home).join(".local").join("share"),
+        _ => std::env::temp_dir(),
+    };
+    data_directory.join("utils").join("quota.json")
+}
+
+/// The ledger every attempt of a request `web` makes to a service (see `service`) is counted in, kept at `default_path()`.
+pub fn global() -> &'static QuotaLedger {
+    static GLOBAL: std::sync::OnceLock<QuotaLedger> = std::sync::OnceLock::new();
+    GLOBAL.get_or_init(|| QuotaLedger::new(&default_path()))
+}
@@ -0,0 +1,223 @@
+//! MIT license.
+
+/// The pace of requests allowed to a host: a sustained rate, and a burst of requests that may be made at once.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RateLimit {
+    pub requests_per_second: f64,
+    pub burst: u32,
+}
+
+/// The EnsEMBL REST API allows 55,000 requests an hour, approximately 15 requests a second.
+pub const ENSEMBL_RATE_LIMIT: RateLimit = RateLimit { requests_per_second: 15.0, burst: 15 };
+/// The University of California Santa Cruz Genome Browser's REST API allows approximately one request every 15 seconds.
+pub const UCSC_RATE_LIMIT: RateLimit = RateLimit { requests_per_second: 1.0 / 15.0, burst: 1 };
+
+/// A token bucket of a host.
+///
+/// Tokens below zero are requests reserved, and waiting, for tokens yet to be added. No tokens are added while the
+/// host has paused requests, so the requests waiting for the pause to end are spaced at the host's rate after it.
+#[derive(Debug)]
+struct Bucket {
+    limit: RateLimit,
+    /// The rate tokens are added at: the limit's rate, or a slower rate the host has asked for.
+    requests_per_second: f64,
+    tokens: f64,
+    updated: std::time::Instant,
+    blocked_until: Option<std::time::Instant>,
+}
+
+impl Bucket {
+    fn new(limit: RateLimit) -> Bucket {
+        Bucket {
+            limit,
+            requests_per_second: limit.requests_per_second,
+            tokens: f64::from(limit.burst),
+            updated: std::time::Instant::now(),
+            blocked_until: None,
+        }
+    }
+
+    /// Add the tokens of the time elapsed since the bucket was updated, or since the pause of the host ended.
+    fn refill(&mut self, now: std::time::Instant) {
+        let start: std::time::Instant = self.updated.max(self.blocked_until.unwrap_or(self.updated));
+        let elapsed: f64 = now.saturating_duration_since(start).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.requests_per_second).min(f64::from(self.limit.burst));
+        self.updated = now.max(start);
+    }
+
+    /// Reserve a token, returning how long to wait before making the request: until the pause of the host ends,
+    /// if it has paused requests, and then until the tokens reserved before this one have been added.
+    fn reserve(&mut self, now: std::time::Instant) -> std::time::Duration {
+        self.refill(now);
+        self.tokens -= 1.0;
+        let blocked: std::time::Duration = self.updated.saturating_duration_since(now);
+        let deficit: std::time::Duration = match self.tokens < 0.0 && self.requests_per_second > 0.0 {
+            true => std::time::Duration::from_secs_f64(-self.tokens / self.requests_per_second),
+            false => std::time::Duration::ZERO,
+        };
+        blocked + deficit
+    }
+}
+
+/// A rate limiter of requests to each host, shared by every request to the host, including those of concurrent tasks.
+///
+/// Hosts without a rate limit are not limited. A host's responses may slow its pace down, see `RateLimiter::observe`.
+///
+/// # Example
+///
+/// ```
+/// use utils::rate_limit::{RateLimit, RateLimiter};
+///
+/// let limiter: RateLimiter = RateLimiter::new();
+/// limiter.set_limit("rest.ensembl.org", RateLimit { requests_per_second: 20.0, burst: 2 });
+///
+/// let runtime = actix_web::rt::Runtime::new().unwrap();
+/// let moment = std::time::Instant::now();
+/// runtime.block_on(async {
+///     // The burst of two requests is made at once, the next two requests wait 50 milliseconds each.
+///     for _ in 0..4 {
+///         limiter.acquire("https://rest.ensembl.org/lookup/id/ENSG00000155542").await;
+///     }
+///     // Other hosts are not limited.
+///     limiter.acquire("https://api.genome.ucsc.edu/list/ucscGenomes").await;
+/// });
+/// assert!(moment.elapsed() >= std::time::Duration::from_millis(100));
+/// assert!(moment.elapsed() < std::time::Duration::from_millis(1_000));
+/// ```
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    buckets: std::sync::Mutex<std::collections::HashMap<String, Bucket>>,
+}
+
+impl RateLimiter {
+    /// A rate limiter without any rate limits.
+    pub fn new() -> RateLimiter {
+        RateLimiter::default()
+    }
+
+    /// Set, or replace, the rate limit of a host, such as `rest.ensembl.org`.
+    pub fn set_limit(&self, host: &str, limit: RateLimit) {
+        self.lock().insert(String::from(host), Bucket::new(limit));
+    }
+
+    /// The rate limit of a host, if the host is limited.
+    pub fn get_limit(&self, host: &str) -> Option<RateLimit> {
+        self.lock().get(host).map(|bucket| bucket.limit)
+    }
+
+    /// Wait until a request may be made to the host of a URL.
+    pub async fn acquire(&self, url: &str) {
+        let wait: std::time::Duration = match host(url) {
+            Some(host) => match self.lock().get_mut(&host) {
+                Some(bucket) => bucket.reserve(std::time::Instant::now()),
+                None => std::time::Duration::ZERO,
+            },
+            None => std::time::Duration::ZERO,
+        };
+        if !wait.is_zero() {
+            actix_web::rt::time::sleep(wait).await;
+        }
+    }
+
+    /// Adapt the pace of requests to a host from the headers of its response to a URL.
+    ///
+    /// A `Retry-After` header, given in seconds, pauses requests to the host. EnsEMBL's `X-RateLimit-Remaining`
+    /// and `X-RateLimit-Reset` headers pause requests once none remain until the limit resets, and otherwise
+    /// spread the requests remaining over the time until the reset, if that is slower than the host's rate limit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use utils::rate_limit::{RateLimit, RateLimiter};
+    ///
+    /// let limiter: RateLimiter = RateLimiter::new();
+    /// limiter.set_limit("rest.ensembl.org", RateLimit { requests_per_second: 15.0, burst: 15 });
+    ///
+    /// let mut headers = awc::http::header::HeaderMap::new();
+    /// headers.insert(awc::http::header::RETRY_AFTER, awc::http::header::HeaderValue::from_static("1"));
+    /// limiter.observe("https://rest.ensembl.org/lookup/id/ENSG00000155542", &headers);
+    ///
+    /// // The requests waiting for the pause to end are spaced at the host's rate, rather than made at once when it ends.
+    /// let limiter: std::rc::Rc<RateLimiter> = std::rc::Rc::new(limiter);
+    /// let runtime = actix_web::rt::Runtime::new().unwrap();
+    /// let moment = std::time::Instant::now();
+    /// let mut elapsed: Vec<std::time::Duration> = runtime.block_on(async {
+    ///     let tasks: Vec<actix_web::rt::task::JoinHandle<std::time::Duration>> = (0..3)
+    ///         .map(|_| {
+    ///             let limiter: std::rc::Rc<RateLimiter> = std::rc::Rc::clone(&limiter);
+    ///             actix_web::rt::spawn(async move {
+    ///                 limiter.acquire("https://rest.ensembl.org/lookup/id/ENSG00000155542").await;
+    ///                 moment.elapsed()
+    ///             })
+    ///         })
+    ///         .collect();
+    ///     let mut elapsed: Vec<std::time::Duration> = Vec::new();
+    ///     for task in tasks {
+    ///         elapsed.push(task.await.unwrap());
+    ///     }
+    ///     elapsed
+    /// });
+    /// elapsed.sort();
+    /// assert!(elapsed[0] >= std::time::Duration::from_millis(900));
+    /// assert!(elapsed[1] - elapsed[0] >= std::time::Duration::from_millis(50));
+    /// assert!(elapsed[2] - elapsed[1] >= std::time::Duration::from_millis(50));
+    /// ```
+    pub fn observe(&self, url: &str, headers: &awc::http::header::HeaderMap) {
+        let Some(host) = host(url) else {
+            return;
+        };
+        let header = |name: &str| -> Option<f64> {
+            headers.get(name).and_then(|value| value.to_str().ok()).and_then(|value| value.trim().parse::<f64>().ok())
+        };
+        let retry_after: Option<f64> = header("retry-after");
+        let remaining: Option<f64> = header("x-ratelimit-remaining");
+        let reset: Option<f64> = header("x-ratelimit-reset");
+
+        let mut buckets = self.lock();
+        let Some(bucket) = buckets.get_mut(&host) else {
+            return;
+        };
+        let now: std::time::Instant = std::time::Instant::now();
+        bucket.refill(now);
+
+        let mut pause: Option<f64> = retry_after;
+        match (remaining, reset) {
+            (Some(remaining), Some(reset)) if remaining < 1.0 => pause = Some(pause.map_or(reset, |pause| pause.max(reset))),
+            (Some(remaining), Some(reset)) if reset > 0.0 => {
+                bucket.requests_per_second = bucket.limit.requests_per_second.min(remaining / reset);
+            }
+            _ => bucket.requests_per_second = bucket.limit.requests_per_second,
+        }
+
+        if let Some(seconds) = pause.filter(|seconds| seconds.is_finite() && *seconds > 0.0) {
+            let until: std::time::Instant = now + std::time::Duration::from_secs_f64(seconds);
+            bucket.blocked_until = bucket.blocked_until.max(Some(until));
+            bucket.tokens = bucket.tokens.min(0.0);
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, std::collections::HashMap<String, Bucket>> {
+        // A panic while the buckets are locked leaves them consistent, so a poisoned lock is recovered.
+        self.buckets.lock().unwrap_or_else(|error| error.into_inner())
+    }
+}
+
+/// The rate limiter of every request made by `web`, limiting EnsEMBL and the UCSC Genome Browser by default.
+///
+/// Set a rate limit for other hosts, such as mirrors, with `RateLimiter::set_limit`.
+pub fn global() -> &'static RateLimiter {
+    static GLOBAL: std::sync::OnceLock<RateLimiter> = std::sync::OnceLock::new();
+    GLOBAL.get_or_init(|| {
+        let limiter: RateLimiter = RateLimiter::new();
+        limiter.set_limit("rest.ensembl.org", ENSEMBL_RATE_LIMIT);
+        limiter.set_limit("grch37.rest.ensembl.org", ENSEMBL_RATE_LIMIT);
+        limiter.set_limit("api.genome.ucsc.edu", UCSC_RATE_LIMIT);
+        limiter
+    })
+}
+
+/// The host of a URL.
+fn host(url: &str) -> Option<String> {
+    let uri: awc::http::Uri = url.parse().ok()?;
+    uri.host().map(String::from)
+}
@@ -1,21 +1,143 @@
 //! MIT license.
 
+pub mod approximate;
+pub mod io;
+pub mod kmer;
+pub mod nucleotide;
+pub mod repeat;
+pub mod statistics;
+pub mod suffix_array;
+pub mod tandem;
+pub mod translation;
+
 use serde::ser::SerializeStruct;
 
+/// The strand of a double-stranded DNA sequence.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum Strand {
+    #[default]
+    Forward,
+    Reverse,
+}
+
+impl std::fmt::Display for Strand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Strand::Forward => write!(f, "1"),
+            Strand::Reverse => write!(f, "-1"),
+        }
+    }
+}
+
+/// Parse a strand from EnsEMBL's `1` and `-1` or the `+` and `-` notation.
+impl std::str::FromStr for Strand {
+    type Err = ParseStrandError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string.trim() {
+            "1" | "+1" | "+" => Ok(Strand::Forward),
+            "-1" | "-" => Ok(Strand::Reverse),
+            strand => Err(ParseStrandError {
+                strand: String::from(strand),
+            }),
+        }
+    }
+}
+
+impl serde::ser::Serialize for Strand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        match self {
+            Strand::Forward => serializer.serialize_i8(1),
+            Strand::Reverse => serializer.serialize_i8(-1),
+        }
+    }
+}
+
+/// Deserialize a strand from the number 1 or -1, or from any string `Strand::from_str` accepts.
+impl<'de> serde::de::Deserialize<'de> for Strand {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct StrandVisitor;
+
+        impl serde::de::Visitor<'_> for StrandVisitor {
+            type Value = Strand;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "a strand: 1 or -1")
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, strand: i64) -> Result<Strand, E> {
+                self.visit_str(&strand.to_string())
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, strand: u64) -> Result<Strand, E> {
+                self.visit_str(&strand.to_string())
+            }
+
+            fn visit_str<E: serde::de::Error>(self, strand: &str) -> Result<Strand, E> {
+                strand.parse::<Strand>().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(StrandVisitor)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseStrandError {
+    strand: String,
+}
+
+impl std::fmt::Display for ParseStrandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "invalid strand: \"{}\"", self.strand)
+    }
+}
+
+impl std::error::Error for ParseStrandError {}
+
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub struct Sequence {
     start: usize,
     end: usize,
+    strand: Strand,
 }
 
 impl Sequence {
     pub fn new(start: usize, end: usize) -> Sequence {
         Sequence {
-            start: start,
-            end: end,
+            start,
+            end,
+            strand: Strand::Forward,
         }
     }
 
+    /// Create a sequence on a strand. The start and end are always positions on the forward strand.
+    pub fn with_strand(start: usize, end: usize, strand: Strand) -> Sequence {
+        Sequence {
+            start,
+            end,
+            strand,
+        }
+    }
+
+    pub fn get_start(&self) -> usize {
+        self.start
+    }
+
+    pub fn get_end(&self) -> usize {
+        self.end
+    }
+
+    pub fn get_strand(&self) -> Strand {
+        self.strand
+    }
+
     pub fn to_json(&self) -> Result<String, Box<dyn std::error::Error>>{
         Ok(serde_json::to_string(&self)?)
     }
@@ -26,44 +148,182 @@ impl serde::ser::Serialize for Sequence {
     where
         S: serde::ser::Serializer, 
     {
-        let mut serialized_sequence = serializer.serialize_struct("Sequence", 2)?;
+        let mut serialized_sequence = serializer.serialize_struct("Sequence", 3)?;
         serialized_sequence.serialize_field("start", &self.start)?;
         serialized_sequence.serialize_field("end", &self.end)?;
+        serialized_sequence.serialize_field("strand", &self.strand)?;
         serialized_sequence.end()
     }
 }
 
+/// Return the complement of an IUPAC nucleotide code, preserving its case. Other characters are returned unchanged.
+pub fn complement(nucleotide: char) -> char {
+    let complement: char = match nucleotide.to_ascii_uppercase() {
+        'A' => 'T',
+        'C' => 'G',
+        'G' => 'C',
+        'T' | 'U' => 'A',
+        'R' => 'Y',
+        'Y' => 'R',
+        'K' => 'M',
+        'M' => 'K',
+        'B' => 'V',
+        'V' => 'B',
+        'D' => 'H',
+        'H' => 'D',
+        _ => return nucleotide,
+    };
+    if nucleotide.is_ascii_lowercase() {
+        complement.to_ascii_lowercase()
+    } else {
+        complement
+    }
+}
+
+/// Return the reverse complement of a DNA sequence, preserving the case of each nucleotide.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(utils::seq::reverse_complement("GATTacaN"), "NtgtAATC");
+/// ```
+pub fn reverse_complement(dna: &str) -> String {
+    dna.chars().rev().map(complement).collect()
+}
+
+/// Separates the forward strand from its reverse complement in an index of both strands.
+const STRAND_SEPARATOR: u8 = b'\0';
+
+/// An index over a sequence of UTF-8 encoded characters backed by a suffix array.
+#[derive(Default)]
 pub struct Sequences {
-    data: std::collections::BTreeMap<
-        String,
-        std::sync::Arc<std::sync::Mutex<std::collections::HashMap<String, Sequence>>>,
-    >,
+    index: suffix_array::SuffixArray,
+    both_strands: bool,
 }
 
 impl Sequences {
     pub fn new() -> Sequences {
         Sequences {
-            data: std::collections::BTreeMap::new(),
+            index: suffix_array::SuffixArray::default(),
+            both_strands: false,
         }
     }
 
-    /// Given a string to match on, this method returns a JSON string representing all matching positions.
+    /// Index a DNA sequence and its reverse complement, so that matches are found on both strands.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let sequences = utils::seq::Sequences::from_both_strands(String::from("GAATTCAAGGT"));
+    /// let result: Vec<utils::seq::Sequence> = sequences.find("ACCT");
+    /// assert_eq!(result, vec![utils::seq::Sequence::with_strand(7, 11, utils::seq::Strand::Reverse)]);
+    /// // The palindromic EcoRI site is found on both strands.
+    /// assert_eq!(sequences.find("GAATTC").len(), 2);
+    /// ```
+    pub fn from_both_strands(string: String) -> Sequences {
+        if string.is_empty() {
+            return Sequences::new();
+        }
+
+        let mut text: Vec<u8> = Vec::with_capacity(2 * string.len() + 1);
+        text.extend_from_slice(string.as_bytes());
+        text.push(STRAND_SEPARATOR);
+        text.extend_from_slice(reverse_complement(&string).as_bytes());
+        Sequences {
+            index: suffix_array::SuffixArray::new(text),
+            both_strands: true,
+        }
+    }
+
+    /// Whether the reverse complement of the sequence is indexed.
+    pub fn has_both_strands(&self) -> bool {
+        self.both_strands
+    }
+
+    /// Given a string to match on, this method returns all matching positions ordered by their start.
     pub fn find(&self, string: &str) -> Vec<Sequence> {
-        let mut matches: Vec<Sequence> = Vec::new();
+        // A match spanning both strands is not a match.
+        if string.is_empty() || (self.both_strands && string.as_bytes().contains(&STRAND_SEPARATOR)) {
+            return Vec::new();
+        }
 
-        match self.data.get(string) {
-            Some(map) => {
-                for (_, entry) in map.lock().unwrap().iter() {
-                    matches.push(entry.clone());
-                }
-            }
-            None => {}
-        };
-        return matches;
+        let suffixes: &[usize] = self.index.get_suffixes();
+        let mut matches: Vec<Sequence> = self.index.find(string.as_bytes())
+            .map(|rank| self.locate(suffixes[rank], string.len()))
+            .collect();
+        matches.sort();
+        matches
+    }
+
+    /// Given a string to match on, return the positions of sub-strings within `maximum_distance` mismatches
+    /// (Hamming distance) or edits (Levenshtein distance) of the string, with the distance of each match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use utils::seq::approximate::Distance;
+    ///
+    /// let sequences = utils::seq::Sequences::from(String::from("GATTACAGGATTTCA"));
+    /// let hamming = sequences.find_approximate("GATTACA", 1, Distance::Hamming);
+    /// assert_eq!(hamming.len(), 2);
+    /// assert_eq!(hamming[1].get_sequence(), utils::seq::Sequence::new(8, 15));
+    /// assert_eq!(hamming[1].get_distance(), 1);
+    ///
+    /// let levenshtein = sequences.find_approximate("GATTTACA", 1, Distance::Levenshtein);
+    /// assert_eq!(levenshtein[0].get_sequence(), utils::seq::Sequence::new(0, 7));
+    /// assert_eq!(levenshtein[0].get_distance(), 1);
+    /// ```
+    pub fn find_approximate(
+        &self,
+        string: &str,
+        maximum_distance: usize,
+        distance: approximate::Distance,
+    ) -> Vec<approximate::ApproximateMatch> {
+        approximate::find_approximate(self, string.as_bytes(), maximum_distance, distance)
+    }
+
+    /// The length of the indexed forward strand.
+    fn forward_length(&self) -> usize {
+        match self.both_strands {
+            true => (self.index.len() - 1) / 2,
+            false => self.index.len(),
+        }
+    }
+
+    /// The indexed strands, with the position each starts at in the index.
+    fn strands(&self) -> Vec<(usize, &[u8])> {
+        let text: &[u8] = self.index.get_text();
+        match self.both_strands {
+            true => vec![
+                (0, &text[..self.forward_length()]),
+                (self.forward_length() + 1, &text[self.forward_length() + 1..]),
+            ],
+            false => vec![(0, text)],
+        }
+    }
+
+    /// Convert a match of `length` bytes at a position in the index to a sequence on the forward strand's positions.
+    fn locate(&self, position: usize, length: usize) -> Sequence {
+        let forward_length: usize = self.forward_length();
+        if position < forward_length {
+            return Sequence::new(position, position + length);
+        }
+        let offset: usize = position - forward_length - 1;
+        Sequence::with_strand(
+            forward_length - offset - length,
+            forward_length - offset,
+            Strand::Reverse,
+        )
     }
 
     /// Given a sequence of UTF-8 encoded characters, return a vector of sub-strings found in the sequence as a vector of Strings.
     ///
+    /// The sub-strings are the sequence's characters, the sequence's prefixes, its runs of a single repeating
+    /// character and every sub-string occurring more than once, in lexicographical order.
+    /// When both strands are indexed, the sub-strings of the reverse complement are included.
+    ///
+    /// The sub-strings are read from the suffix array in a single pass: each distinct sub-string is listed by the
+    /// first suffix it is a prefix of, which shares at most `lcp` bytes with the suffix preceding it.
     ///
     /// # Example
     ///
@@ -74,111 +334,132 @@ impl Sequences {
     /// assert_eq!(result, expected);
     /// ```
     pub fn get_tokens(&self) -> Vec<String> {
-        let mut tokens: Vec<String> = Vec::new();
-        for key in self.data.keys() {
-            tokens.push(key.clone());
-        }
-        return tokens;
-    }
+        let Ok(text) = std::str::from_utf8(self.index.get_text()) else {
+            return Vec::new();
+        };
+        let bytes: &[u8] = text.as_bytes();
+        let suffixes: &[usize] = self.index.get_suffixes();
+        let lcp: &[usize] = self.index.get_lcp();
+        let strands: Vec<(usize, &[u8])> = self.strands();
 
-    fn insert(&mut self, key: &String, sequence: Sequence) {
-        let new_sequence = Sequence::new(sequence.start, sequence.end);
-        match self.data.get_mut(key) {
-            Some(value) => {
-                let index: String = format!("{}{}", sequence.start, sequence.end);
-                value.lock().unwrap().insert(index.clone(), sequence);
+        // The length of the run of a single repeating byte starting at each position.
+        let mut runs: Vec<usize> = vec![1; bytes.len()];
+        for position in (0..bytes.len().saturating_sub(1)).rev() {
+            if bytes[position] == bytes[position + 1] {
+                runs[position] = runs[position + 1] + 1;
             }
-            None => {
-                self.data.insert(
-                    String::from(key),
-                    std::sync::Arc::new(std::sync::Mutex::new(std::collections::HashMap::from([
-                        (
-                            format!("{}{}", sequence.start, sequence.end).clone(),
-                            new_sequence,
-                        ),
-                    ]))),
-                );
+        }
+
+        let mut tokens: Vec<&str> = Vec::new();
+        for (rank, &position) in suffixes.iter().enumerate() {
+            // The strand separator starts no sub-string, and no sub-string crosses it.
+            let Some(&(offset, strand)) = strands
+                .iter()
+                .find(|(offset, strand)| (*offset..offset + strand.len()).contains(&position))
+            else {
+                continue;
+            };
+            if !text.is_char_boundary(position) {
+                continue;
             }
+            let suffix: &str = &text[position..offset + strand.len()];
+
+            // The longest prefix of the suffix that is a sub-string: the suffix itself if it is the sequence,
+            // otherwise the longest of its first character, its run and the prefix shared with the next suffix.
+            let longest: usize = match position == offset {
+                true => suffix.len(),
+                false => {
+                    let character: usize = suffix.chars().next().map_or(0, char::len_utf8);
+                    let shared: usize = lcp.get(rank + 1).copied().unwrap_or(0);
+                    character.max(runs[position]).max(shared).min(suffix.len())
+                }
+            };
+            tokens.extend(
+                (lcp[rank] + 1..=longest)
+                    .filter(|&length| suffix.is_char_boundary(length))
+                    .map(|length| &suffix[..length]),
+            );
         }
+
+        tokens.into_iter().map(String::from).collect()
+    }
+
+    /// Return the repeats that cannot be extended to the left or to the right without losing an occurrence,
+    /// with at least `minimum_length` bytes and `minimum_count` occurrences.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let sequences = utils::seq::Sequences::from(String::from("xabcyabcwabcyz"));
+    /// let repeats: Vec<utils::seq::repeat::Repeat> = sequences.maximal_repeats(2, 2);
+    /// let result: Vec<&str> = repeats.iter().map(|repeat| repeat.get_sequence()).collect();
+    /// assert_eq!(result, vec!["abc", "abcy"]);
+    /// assert_eq!(repeats[0].get_occurrences().len(), 3);
+    /// ```
+    pub fn maximal_repeats(&self, minimum_length: usize, minimum_count: usize) -> Vec<repeat::Repeat> {
+        repeat::find_repeats(self, repeat::RepeatKind::Maximal, minimum_length, minimum_count)
+    }
+
+    /// Return the maximal repeats that are not a sub-string of any other maximal repeat,
+    /// with at least `minimum_length` bytes and `minimum_count` occurrences.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let sequences = utils::seq::Sequences::from(String::from("xabcyabcwabcyz"));
+    /// let repeats: Vec<utils::seq::repeat::Repeat> = sequences.supermaximal_repeats(1, 2);
+    /// let result: Vec<&str> = repeats.iter().map(|repeat| repeat.get_sequence()).collect();
+    /// assert_eq!(result, vec!["abcy"]);
+    /// ```
+    pub fn supermaximal_repeats(&self, minimum_length: usize, minimum_count: usize) -> Vec<repeat::Repeat> {
+        repeat::find_repeats(self, repeat::RepeatKind::Supermaximal, minimum_length, minimum_count)
     }
 }
 
 impl From<String> for Sequences {
     fn from(string: String) -> Self {
-        let input_length: usize = string.len();
-        let mut sequences: Sequences = Sequences::new();
-        let mut am: Vec<Vec<Sequence>> =
-            vec![vec![Sequence::new(0, 0); input_length + 1]; input_length + 1];
-        // For each character in the sequence,
-        for index_i in 1..=input_length {
-            // Set the current character value.
-            let current_sequence_value: &str = &string[index_i - 1..index_i];
-            // Compare each character in the sequence to a character in the sequence.
-            for index_j in index_i..=input_length {
-                // Assign the previous character in the sequence to a variable.
-                let previous_sequence_value: &str = &string[index_j - 1..index_j];
-                // If the current character being evaluated matches the previous character,
-                if current_sequence_value == previous_sequence_value {
-                    if index_j - index_i == 0 {
-                        // Add the single character's position as an entry to Sequences.
-                        let key = String::from(&string[index_j - 1..index_j]);
-                        // Insert the matching sequence into a B-Tree.
-                        sequences.insert(&key, Sequence::new(index_j - 1, index_j));
-                        // Add the accumulated sequence to the adjaceny matrix.
-                        am[index_i][index_j].start = am[index_i - 1][index_j - 1].start;
-                        am[index_i][index_j].end = am[index_i - 1][index_j - 1].end + 1;
-                    } else if index_j - index_i == 1 && am[index_i - 1][index_j - 1].end == 0 {
-                        // If a repeating character is encountered for the first time,
-                        // Add the beginning character's index and the index of the current character
-                        // to the adjacency matrix.
-                        am[index_i][index_j].start = index_j - 2;
-                        am[index_i][index_j].end = index_j;
-                    } else {
-                        // For all other sequence matches,
-                        // Add the beginning sequences's index as an offset from the matched index at `index_j`
-                        // and the ending sequence as `index_j`.
-                        am[index_i][index_j].start = index_j
-                            - (am[index_i - 1][index_j - 1].end
-                                - am[index_i - 1][index_j - 1].start)
-                            - 1;
-                        am[index_i][index_j].end = index_j;
-                        // Add the sequence excluding the very beginning of the start index.
-                        if am[index_i][index_j].end - am[index_i][index_j].start > 1 {
-                            let key: String = String::from(
-                                &string[am[index_i][index_j].start + 1..am[index_i][index_j].end],
-                            );
-                            sequences.insert(
-                                &key,
-                                Sequence::new(
-                                    am[index_i][index_j].start + 1,
-                                    am[index_i][index_j].end,
-                                ),
-                            );
-                        }
-                    }
-                    // Add the sequence as an entry to Sequences.
-                    let key: String =
-                        String::from(&string[am[index_i][index_j].start..am[index_i][index_j].end]);
-                    sequences.insert(
-                        &key,
-                        Sequence::new(am[index_i][index_j].start, am[index_i][index_j].end),
-                    );
-                }
-            }
+        Sequences {
+            index: suffix_array::SuffixArray::new(string.into_bytes()),
+            both_strands: false,
+        }
+    }
+}
+
+/// Index a DNA sequence by its upper-case IUPAC codes, read from its packed nucleotides, so that soft-masked
+/// and unmasked nucleotides match.
+///
+/// # Example
+///
+/// ```
+/// let dna: utils::seq::nucleotide::DnaSequence = "ACTgggact".parse().unwrap();
+/// let sequences = utils::seq::Sequences::from(dna);
+/// assert_eq!(sequences.find("ACT"), vec![utils::seq::Sequence::new(0, 3), utils::seq::Sequence::new(6, 9)]);
+/// ```
+impl From<nucleotide::DnaSequence> for Sequences {
+    fn from(dna: nucleotide::DnaSequence) -> Self {
+        Sequences::from(&dna)
+    }
+}
+
+/// Index a borrowed DNA sequence, such as a gene's DNA, in the same way.
+impl From<&nucleotide::DnaSequence> for Sequences {
+    fn from(dna: &nucleotide::DnaSequence) -> Self {
+        Sequences {
+            index: suffix_array::SuffixArray::new(dna.to_unmasked_bytes()),
+            both_strands: false,
         }
-        return sequences;
     }
 }
 
 impl std::fmt::Display for Sequences {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         let mut text: String = String::new();
-        for (key, value) in self.data.iter() {
+        for key in self.get_tokens() {
             text.push_str(&format!("sequence: {}:\n", key));
-            for (_, sequence) in value.lock().unwrap().iter() {
+            for sequence in self.find(&key) {
                 text.push_str(&format!(
-                    "\tstart: {}, end: {}\n",
-                    sequence.start, sequence.end
+                    "\tstart: {}, end: {}, strand: {}\n",
+                    sequence.start, sequence.end, sequence.strand
                 ));
             }
         }
@@ -248,6 +529,14 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_get_tokens_on_multibyte_characters() {
+        let string: String = String::from("aéaéé");
+        let expected: Vec<&str> = vec!["a", "aé", "aéa", "aéaé", "aéaéé", "é"];
+        let result: Vec<String> = Sequences::from(string).get_tokens();
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn test_find_on_multiple_occurrences() {
         let string: String = String::from("actact");
@@ -257,6 +546,64 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_maximal_repeats_on_duplicate_string() {
+        let sequences: Sequences = Sequences::from(String::from("actact"));
+        let expected: Vec<repeat::Repeat> = vec![repeat::Repeat::new(
+            "act",
+            vec![Sequence::new(0, 3), Sequence::new(3, 6)],
+        )];
+        assert_eq!(sequences.maximal_repeats(1, 2), expected);
+        assert_eq!(sequences.supermaximal_repeats(1, 2), expected);
+    }
+
+    #[test]
+    fn test_maximal_repeats_on_minimum_count() {
+        let sequences: Sequences = Sequences::from(String::from("gactgggact"));
+        let result: Vec<String> = sequences
+            .maximal_repeats(1, 3)
+            .iter()
+            .map(|repeat| String::from(repeat.get_sequence()))
+            .collect();
+        assert_eq!(result, vec!["g"]);
+    }
+
+    #[test]
+    fn test_maximal_repeats_on_repeating_character() {
+        let sequences: Sequences = Sequences::from(String::from("gggg"));
+        let result: Vec<String> = sequences
+            .maximal_repeats(1, 2)
+            .iter()
+            .map(|repeat| String::from(repeat.get_sequence()))
+            .collect();
+        assert_eq!(result, vec!["g", "gg", "ggg"]);
+        assert_eq!(sequences.supermaximal_repeats(1, 2)[0].get_occurrences(), &[Sequence::new(0, 3), Sequence::new(1, 4)]);
+        assert!(sequences.maximal_repeats(4, 2).is_empty());
+    }
+
+    #[test]
+    fn test_find_on_both_strands() {
+        let sequences: Sequences = Sequences::from_both_strands(String::from("ACCGGTAAATTT"));
+        let expected: Vec<Sequence> = vec![
+            Sequence::new(0, 6),
+            Sequence::with_strand(0, 6, Strand::Reverse),
+        ];
+        assert_eq!(sequences.find("ACCGGT"), expected);
+        assert_eq!(
+            sequences.find("AAAT"),
+            vec![Sequence::new(6, 10), Sequence::with_strand(8, 12, Strand::Reverse)]
+        );
+        assert!(sequences.find("T\0").is_empty());
+    }
+
+    #[test]
+    fn test_get_tokens_on_both_strands() {
+        let string: String = String::from("aac");
+        let expected: Vec<&str> = vec!["a", "aa", "aac", "c", "g", "gt", "gtt", "t", "tt"];
+        let result: Vec<String> = Sequences::from_both_strands(string).get_tokens();
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn test_find_on_no_matching_occurrences() {
         let string: String = String::from("actact");
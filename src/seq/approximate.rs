@@ -0,0 +1,218 @@
+//! MIT license.
+
+use serde::ser::SerializeStruct;
+
+/// The measure of difference between a pattern and a matching sub-string.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Distance {
+    /// The number of mismatched characters between equal length strings.
+    Hamming,
+    /// The number of mismatched, inserted and deleted characters.
+    Levenshtein,
+}
+
+/// A sub-string within a maximum distance of a pattern.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct ApproximateMatch {
+    sequence: super::Sequence,
+    distance: usize,
+}
+
+impl ApproximateMatch {
+    pub fn new(sequence: super::Sequence, distance: usize) -> ApproximateMatch {
+        ApproximateMatch { sequence, distance }
+    }
+
+    pub fn get_sequence(&self) -> super::Sequence {
+        self.sequence
+    }
+
+    /// The number of mismatches, or edits, between the pattern and the sub-string.
+    pub fn get_distance(&self) -> usize {
+        self.distance
+    }
+
+    pub fn to_json(&self) -> Result<String, Box<dyn std::error::Error>> {
+        Ok(serde_json::to_string(&self)?)
+    }
+}
+
+impl serde::ser::Serialize for ApproximateMatch {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        let mut serialized_match = serializer.serialize_struct("ApproximateMatch", 2)?;
+        serialized_match.serialize_field("sequence", &self.sequence)?;
+        serialized_match.serialize_field("distance", &self.distance)?;
+        serialized_match.end()
+    }
+}
+
+/// Find the sub-strings within `maximum_distance` of a pattern, ordered by position.
+///
+/// Hamming matches are found by walking the suffix array, branching on each mismatch while the number
+/// of mismatches allows. Levenshtein matches are found by aligning the pattern against each indexed strand,
+/// reporting the closest alignment ending at each position where the distance is at a local minimum.
+pub fn find_approximate(
+    sequences: &super::Sequences,
+    pattern: &[u8],
+    maximum_distance: usize,
+    distance: Distance,
+) -> Vec<ApproximateMatch> {
+    if pattern.is_empty() || pattern.contains(&super::STRAND_SEPARATOR) {
+        return Vec::new();
+    }
+
+    let mut matches: Vec<ApproximateMatch> = match distance {
+        Distance::Hamming => {
+            let mut matches: Vec<ApproximateMatch> = Vec::new();
+            let suffixes: std::ops::Range<usize> = 0..sequences.index.get_suffixes().len();
+            find_hamming(sequences, pattern, maximum_distance, suffixes, 0, 0, &mut matches);
+            matches
+        }
+        Distance::Levenshtein => sequences
+            .strands()
+            .into_iter()
+            .flat_map(|(offset, strand)| {
+                find_levenshtein(strand, pattern, maximum_distance)
+                    .into_iter()
+                    .map(move |(start, end, distance)| {
+                        ApproximateMatch::new(sequences.locate(offset + start, end - start), distance)
+                    })
+            })
+            .collect(),
+    };
+    matches.sort();
+    matches.dedup();
+    matches
+}
+
+/// Match the pattern from `depth` onwards against the suffixes in `ranks`, which share the first `depth` bytes
+/// of the pattern with `mismatches` mismatches.
+fn find_hamming(
+    sequences: &super::Sequences,
+    pattern: &[u8],
+    maximum_distance: usize,
+    ranks: std::ops::Range<usize>,
+    depth: usize,
+    mismatches: usize,
+    matches: &mut Vec<ApproximateMatch>,
+) {
+    let text: &[u8] = sequences.index.get_text();
+    let suffixes: &[usize] = sequences.index.get_suffixes();
+
+    if depth == pattern.len() {
+        for rank in ranks {
+            matches.push(ApproximateMatch::new(
+                sequences.locate(suffixes[rank], pattern.len()),
+                mismatches,
+            ));
+        }
+        return;
+    }
+
+    // The suffixes are ordered by their byte at `depth`, after any suffixes too short to have one.
+    let mut rank: usize = ranks.start;
+    while rank < ranks.end {
+        let Some(&byte) = text.get(suffixes[rank] + depth) else {
+            rank += 1;
+            continue;
+        };
+        let end: usize = rank
+            + suffixes[rank..ranks.end]
+                .partition_point(|&suffix| text[suffix + depth] <= byte);
+        let cost: usize = mismatches + usize::from(byte != pattern[depth]);
+        if byte != super::STRAND_SEPARATOR && cost <= maximum_distance {
+            find_hamming(sequences, pattern, maximum_distance, rank..end, depth + 1, cost, matches);
+        }
+        rank = end;
+    }
+}
+
+/// Align the pattern against every sub-string of `text`, returning the start, end and edit distance
+/// of the alignments within `maximum_distance` that end where the distance is at a local minimum.
+fn find_levenshtein(text: &[u8], pattern: &[u8], maximum_distance: usize) -> Vec<(usize, usize, usize)> {
+    // The distance and start of the closest alignment of each pattern prefix ending at the current text position.
+    let mut distances: Vec<usize> = (0..=pattern.len()).collect();
+    let mut starts: Vec<usize> = vec![0; pattern.len() + 1];
+    // The distance of the whole pattern ending at each text position.
+    let mut ends: Vec<(usize, usize)> = vec![(distances[pattern.len()], 0)];
+
+    for (position, &byte) in text.iter().enumerate() {
+        let mut diagonal: (usize, usize) = (distances[0], starts[0]);
+        // An alignment may start at any position of the text at no cost.
+        distances[0] = 0;
+        starts[0] = position + 1;
+        for row in 1..=pattern.len() {
+            let substitution: (usize, usize) = (diagonal.0 + usize::from(pattern[row - 1] != byte), diagonal.1);
+            let insertion: (usize, usize) = (distances[row] + 1, starts[row]);
+            let deletion: (usize, usize) = (distances[row - 1] + 1, starts[row - 1]);
+            diagonal = (distances[row], starts[row]);
+
+            let mut best: (usize, usize) = substitution;
+            if insertion.0 < best.0 {
+                best = insertion;
+            }
+            if deletion.0 < best.0 {
+                best = deletion;
+            }
+            distances[row] = best.0;
+            starts[row] = best.1;
+        }
+        ends.push((distances[pattern.len()], starts[pattern.len()]));
+    }
+
+    let mut alignments: Vec<(usize, usize, usize)> = Vec::new();
+    for end in 1..ends.len() {
+        let (distance, start) = ends[end];
+        let is_local_minimum: bool = distance < ends[end - 1].0
+            && ends.get(end + 1).is_none_or(|&(next, _)| distance <= next);
+        if distance <= maximum_distance && is_local_minimum && start < end {
+            alignments.push((start, end, distance));
+        }
+    }
+    alignments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sequences(matches: &[ApproximateMatch]) -> Vec<(usize, usize, usize)> {
+        matches
+            .iter()
+            .map(|found| (found.get_sequence().get_start(), found.get_sequence().get_end(), found.get_distance()))
+            .collect()
+    }
+
+    #[test]
+    fn test_find_hamming_on_exact_matches() {
+        let index: crate::seq::Sequences = crate::seq::Sequences::from(String::from("actgggact"));
+        let result: Vec<ApproximateMatch> = index.find_approximate("act", 0, Distance::Hamming);
+        assert_eq!(sequences(&result), vec![(0, 3, 0), (6, 9, 0)]);
+    }
+
+    #[test]
+    fn test_find_hamming_on_mismatches() {
+        let index: crate::seq::Sequences = crate::seq::Sequences::from(String::from("ACGTTCGAACGA"));
+        let result: Vec<ApproximateMatch> = index.find_approximate("ACGT", 1, Distance::Hamming);
+        assert_eq!(sequences(&result), vec![(0, 4, 0), (8, 12, 1)]);
+    }
+
+    #[test]
+    fn test_find_levenshtein_on_insertion() {
+        let index: crate::seq::Sequences = crate::seq::Sequences::from(String::from("TTTGATTACATTT"));
+        let result: Vec<ApproximateMatch> = index.find_approximate("GATACA", 1, Distance::Levenshtein);
+        assert_eq!(sequences(&result), vec![(3, 10, 1)]);
+    }
+
+    #[test]
+    fn test_find_levenshtein_on_both_strands() {
+        let index: crate::seq::Sequences = crate::seq::Sequences::from_both_strands(String::from("CCTGTATCCC"));
+        let result: Vec<ApproximateMatch> = index.find_approximate("GATTACA", 1, Distance::Levenshtein);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].get_sequence().get_strand(), crate::seq::Strand::Reverse);
+        assert_eq!(result[0].get_distance(), 1);
+    }
+}
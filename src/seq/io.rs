@@ -0,0 +1,424 @@
+//! MIT license.
+
+/// The number of sequence characters written per line of a FASTA record.
+pub const FASTA_LINE_WIDTH: usize = 60;
+
+/// A FASTA record: a header line and a sequence.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FastaRecord {
+    id: String,
+    description: String,
+    sequence: String,
+}
+
+impl FastaRecord {
+    pub fn new(id: &str, description: &str, sequence: &str) -> FastaRecord {
+        FastaRecord {
+            id: String::from(id),
+            description: String::from(description),
+            sequence: String::from(sequence),
+        }
+    }
+
+    /// The first word of the header line.
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    /// The remainder of the header line following the identifier.
+    pub fn get_description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn get_sequence(&self) -> &str {
+        &self.sequence
+    }
+
+    /// Parse the sequence as DNA.
+    pub fn get_dna_sequence(&self) -> Result<super::nucleotide::DnaSequence, super::nucleotide::NucleotideError> {
+        self.sequence.parse::<super::nucleotide::DnaSequence>()
+    }
+}
+
+/// A FASTQ record: a header line, a sequence and the Phred quality of each base in the sequence.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FastqRecord {
+    id: String,
+    description: String,
+    sequence: String,
+    quality: String,
+}
+
+impl FastqRecord {
+    pub fn new(id: &str, description: &str, sequence: &str, quality: &str) -> FastqRecord {
+        FastqRecord {
+            id: String::from(id),
+            description: String::from(description),
+            sequence: String::from(sequence),
+            quality: String::from(quality),
+        }
+    }
+
+    /// The first word of the header line.
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    /// The remainder of the header line following the identifier.
+    pub fn get_description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn get_sequence(&self) -> &str {
+        &self.sequence
+    }
+
+    /// The quality line as written, one ASCII character per base.
+    pub fn get_quality(&self) -> &str {
+        &self.quality
+    }
+
+    /// The Phred quality score of each base, decoded from the Sanger (offset 33) encoding.
+    pub fn get_quality_scores(&self) -> Vec<u8> {
+        self.quality.bytes().map(|score| score.saturating_sub(33)).collect()
+    }
+
+    /// Parse the sequence as DNA.
+    pub fn get_dna_sequence(&self) -> Result<super::nucleotide::DnaSequence, super::nucleotide::NucleotideError> {
+        self.sequence.parse::<super::nucleotide::DnaSequence>()
+    }
+}
+
+/// Open a file for buffered reading, decompressing it when it is gzip compressed.
+pub fn open(path: &std::path::Path) -> std::io::Result<Box<dyn std::io::BufRead>> {
+    let mut reader: std::io::BufReader<std::fs::File> = std::io::BufReader::new(std::fs::File::open(path)?);
+    let is_gzip: bool = std::io::BufRead::fill_buf(&mut reader)?.starts_with(&[0x1f, 0x8b]);
+    match is_gzip {
+        true => Ok(Box::new(std::io::BufReader::new(flate2::bufread::MultiGzDecoder::new(reader)))),
+        false => Ok(Box::new(reader)),
+    }
+}
+
+/// Split a header line, without its leading marker, into an identifier and a description.
+fn split_header(header: &str) -> (String, String) {
+    match header.trim().split_once(char::is_whitespace) {
+        Some((id, description)) => (String::from(id), String::from(description.trim())),
+        None => (String::from(header.trim()), String::new()),
+    }
+}
+
+fn invalid_data(line: usize, message: &str) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, format!("line {line}: {message}"))
+}
+
+/// A streaming reader of multi-record, line-wrapped FASTA.
+///
+/// Blank lines and `;` comment lines are skipped.
+///
+/// # Example
+///
+/// ```
+/// let fasta: &[u8] = b">ENSG00000155542 5:56909260-56925532\nGACAGCCGTG\nACAGAG\n>ENSG00000162994\nTTTTTATGT\n";
+/// let records: Vec<utils::seq::io::FastaRecord> = utils::seq::io::FastaReader::new(fasta)
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+/// assert_eq!(records.len(), 2);
+/// assert_eq!(records[0].get_id(), "ENSG00000155542");
+/// assert_eq!(records[0].get_description(), "5:56909260-56925532");
+/// assert_eq!(records[0].get_sequence(), "GACAGCCGTGACAGAG");
+/// ```
+pub struct FastaReader<R: std::io::BufRead> {
+    reader: R,
+    header: Option<String>,
+    line_number: usize,
+}
+
+impl<R: std::io::BufRead> FastaReader<R> {
+    pub fn new(reader: R) -> FastaReader<R> {
+        FastaReader {
+            reader,
+            header: None,
+            line_number: 0,
+        }
+    }
+}
+
+impl FastaReader<Box<dyn std::io::BufRead>> {
+    /// Open a FASTA file, which may be gzip compressed.
+    pub fn from_path(path: &std::path::Path) -> std::io::Result<FastaReader<Box<dyn std::io::BufRead>>> {
+        Ok(FastaReader::new(open(path)?))
+    }
+}
+
+impl<R: std::io::BufRead> Iterator for FastaReader<R> {
+    type Item = std::io::Result<FastaRecord>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut sequence: String = String::new();
+        let mut line: String = String::new();
+        loop {
+            line.clear();
+            match self.reader.read_line(&mut line) {
+                Ok(0) => break,
+                Ok(_) => self.line_number += 1,
+                Err(error) => return Some(Err(error)),
+            }
+            let trimmed: &str = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with(';') {
+                continue;
+            }
+            if let Some(header) = trimmed.strip_prefix('>') {
+                // The header of the next record ends the current record.
+                match self.header.replace(String::from(header)) {
+                    Some(previous) => {
+                        let (id, description) = split_header(&previous);
+                        return Some(Ok(FastaRecord { id, description, sequence }));
+                    }
+                    None => continue,
+                }
+            }
+            if self.header.is_none() {
+                return Some(Err(invalid_data(self.line_number, "sequence found before a '>' header line")));
+            }
+            sequence.extend(trimmed.split_whitespace());
+        }
+
+        self.header.take().map(|header| {
+            let (id, description) = split_header(&header);
+            Ok(FastaRecord { id, description, sequence })
+        })
+    }
+}
+
+/// A streaming reader of multi-record FASTQ, allowing line-wrapped sequence and quality lines.
+///
+/// # Example
+///
+/// ```
+/// let fastq: &[u8] = b"@read1 sample=1\nGATTACA\n+\nIIIII#!\n@read2\nACGT\n+read2\n5555\n";
+/// let records: Vec<utils::seq::io::FastqRecord> = utils::seq::io::FastqReader::new(fastq)
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+/// assert_eq!(records.len(), 2);
+/// assert_eq!(records[0].get_sequence(), "GATTACA");
+/// assert_eq!(records[0].get_quality_scores(), vec![40, 40, 40, 40, 40, 2, 0]);
+/// assert_eq!(records[1].get_id(), "read2");
+/// ```
+pub struct FastqReader<R: std::io::BufRead> {
+    reader: R,
+    line_number: usize,
+}
+
+impl<R: std::io::BufRead> FastqReader<R> {
+    pub fn new(reader: R) -> FastqReader<R> {
+        FastqReader {
+            reader,
+            line_number: 0,
+        }
+    }
+
+    /// Read the next non-blank line, returning `None` at the end of the input.
+    fn read_line(&mut self) -> std::io::Result<Option<String>> {
+        let mut line: String = String::new();
+        loop {
+            line.clear();
+            if self.reader.read_line(&mut line)? == 0 {
+                return Ok(None);
+            }
+            self.line_number += 1;
+            if !line.trim().is_empty() {
+                return Ok(Some(String::from(line.trim())));
+            }
+        }
+    }
+
+    fn read_record(&mut self) -> std::io::Result<Option<FastqRecord>> {
+        let header: String = match self.read_line()? {
+            Some(line) => match line.strip_prefix('@') {
+                Some(header) => String::from(header),
+                None => return Err(invalid_data(self.line_number, "expected a '@' header line")),
+            },
+            None => return Ok(None),
+        };
+
+        // Sequence lines continue until the '+' separator line.
+        let mut sequence: String = String::new();
+        loop {
+            match self.read_line()? {
+                Some(line) if line.starts_with('+') => break,
+                Some(line) => sequence.push_str(&line),
+                None => return Err(invalid_data(self.line_number, "expected a '+' separator line")),
+            }
+        }
+
+        // Quality lines continue until there is a quality score for each base.
+        let mut quality: String = String::new();
+        while quality.len() < sequence.len() {
+            match self.read_line()? {
+                Some(line) => quality.push_str(&line),
+                None => break,
+            }
+        }
+        if quality.len() != sequence.len() {
+            return Err(invalid_data(
+                self.line_number,
+                "the quality line length does not match the sequence length",
+            ));
+        }
+
+        let (id, description) = split_header(&header);
+        Ok(Some(FastqRecord {
+            id,
+            description,
+            sequence,
+            quality,
+        }))
+    }
+}
+
+impl FastqReader<Box<dyn std::io::BufRead>> {
+    /// Open a FASTQ file, which may be gzip compressed.
+    pub fn from_path(path: &std::path::Path) -> std::io::Result<FastqReader<Box<dyn std::io::BufRead>>> {
+        Ok(FastqReader::new(open(path)?))
+    }
+}
+
+impl<R: std::io::BufRead> Iterator for FastqReader<R> {
+    type Item = std::io::Result<FastqRecord>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_record().transpose()
+    }
+}
+
+/// A writer of FASTA records with line-wrapped sequences.
+///
+/// # Example
+///
+/// ```
+/// let mut buffer: Vec<u8> = Vec::new();
+/// let mut writer = utils::seq::io::FastaWriter::new(&mut buffer).with_line_width(4);
+/// writer.write_record(&utils::seq::io::FastaRecord::new("ENSG00000155542", "5:56909260-56909269", "GACAGCCGTG")).unwrap();
+/// drop(writer);
+/// assert_eq!(String::from_utf8(buffer).unwrap(), ">ENSG00000155542 5:56909260-56909269\nGACA\nGCCG\nTG\n");
+/// ```
+pub struct FastaWriter<W: std::io::Write> {
+    writer: W,
+    line_width: usize,
+}
+
+impl<W: std::io::Write> FastaWriter<W> {
+    pub fn new(writer: W) -> FastaWriter<W> {
+        FastaWriter {
+            writer,
+            line_width: FASTA_LINE_WIDTH,
+        }
+    }
+
+    /// Wrap sequences every `line_width` characters, or never when `line_width` is zero.
+    pub fn with_line_width(self, line_width: usize) -> FastaWriter<W> {
+        FastaWriter { line_width, ..self }
+    }
+
+    pub fn write_record(&mut self, record: &FastaRecord) -> std::io::Result<()> {
+        match record.description.is_empty() {
+            true => writeln!(self.writer, ">{}", record.id)?,
+            false => writeln!(self.writer, ">{} {}", record.id, record.description)?,
+        }
+        let sequence: &[u8] = record.sequence.as_bytes();
+        let line_width: usize = match self.line_width {
+            0 => sequence.len().max(1),
+            line_width => line_width,
+        };
+        for line in sequence.chunks(line_width) {
+            self.writer.write_all(line)?;
+            self.writer.write_all(b"\n")?;
+        }
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// A writer of four line FASTQ records.
+pub struct FastqWriter<W: std::io::Write> {
+    writer: W,
+}
+
+impl<W: std::io::Write> FastqWriter<W> {
+    pub fn new(writer: W) -> FastqWriter<W> {
+        FastqWriter { writer }
+    }
+
+    pub fn write_record(&mut self, record: &FastqRecord) -> std::io::Result<()> {
+        match record.description.is_empty() {
+            true => writeln!(self.writer, "@{}", record.id)?,
+            false => writeln!(self.writer, "@{} {}", record.id, record.description)?,
+        }
+        writeln!(self.writer, "{}\n+\n{}", record.sequence, record.quality)
+    }
+
+    pub fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fasta_reader_on_sequence_before_header() {
+        let mut reader = FastaReader::new(&b"ACGT\n>record\nACGT\n"[..]);
+        assert_eq!(reader.next().unwrap().unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_fasta_reader_on_empty_record() {
+        let records: Vec<FastaRecord> = FastaReader::new(&b">first\n\n>second\nAC\nGT\n"[..])
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(records, vec![FastaRecord::new("first", "", ""), FastaRecord::new("second", "", "ACGT")]);
+    }
+
+    #[test]
+    fn test_fastq_reader_on_wrapped_record() {
+        let records: Vec<FastqRecord> = FastqReader::new(&b"@read\nACGT\nAC\n+\nIIII\nII\n"[..])
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(records, vec![FastqRecord::new("read", "", "ACGTAC", "IIIIII")]);
+    }
+
+    #[test]
+    fn test_fastq_reader_on_truncated_quality() {
+        let mut reader = FastqReader::new(&b"@read\nACGT\n+\nII\n"[..]);
+        assert_eq!(reader.next().unwrap().unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_open_on_gzip_file() {
+        use std::io::Write;
+
+        let mut file: tempfile::NamedTempFile = tempfile::NamedTempFile::new().unwrap();
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(b">record\nGATTACA\n").unwrap();
+        file.write_all(&encoder.finish().unwrap()).unwrap();
+
+        let records: Vec<FastaRecord> = FastaReader::from_path(file.path())
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(records, vec![FastaRecord::new("record", "", "GATTACA")]);
+    }
+
+    #[test]
+    fn test_fastq_writer_round_trip() {
+        let record: FastqRecord = FastqRecord::new("read", "lane=1", "ACGT", "II#I");
+        let mut buffer: Vec<u8> = Vec::new();
+        FastqWriter::new(&mut buffer).write_record(&record).unwrap();
+        let records: Vec<FastqRecord> = FastqReader::new(&buffer[..]).collect::<Result<_, _>>().unwrap();
+        assert_eq!(records, vec![record]);
+    }
+}
@@ -0,0 +1,226 @@
+//! MIT license.
+
+use serde::ser::SerializeStruct;
+
+/// The longest k-mer that can be packed, 2 bits per base, into 64 bits.
+pub const MAXIMUM_K: usize = 32;
+
+/// Counts of the k-mers (sub-sequences of length k) in one or more DNA sequences.
+///
+/// K-mers are packed 2 bits per base, and k-mers containing an ambiguous nucleotide are skipped.
+/// When counting canonical k-mers, a k-mer and its reverse complement are counted together
+/// under whichever of the two is lexicographically smaller.
+///
+/// # Example
+///
+/// ```
+/// let dna: utils::seq::nucleotide::DnaSequence = "ACGTTacgNACG".parse().unwrap();
+/// let mut counts = utils::seq::kmer::KmerCounts::new(3, false).unwrap();
+/// counts.add(&dna);
+/// assert_eq!(counts.get("ACG"), 3);
+/// assert_eq!(counts.get("CGT"), 1);
+/// assert_eq!(counts.get_total(), 7);
+/// // Four distinct k-mers occur once, and one occurs three times.
+/// assert_eq!(counts.get_spectrum(), vec![(1, 4), (3, 1)]);
+///
+/// let mut canonical = utils::seq::kmer::KmerCounts::new(3, true).unwrap();
+/// canonical.add(&dna);
+/// assert_eq!(canonical.get("ACG"), 4);
+/// assert_eq!(canonical.get("CGT"), 4);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KmerCounts {
+    k: usize,
+    canonical: bool,
+    counts: std::collections::HashMap<u64, u64>,
+}
+
+impl KmerCounts {
+    /// Create empty counts of k-mers of length `k`, between 1 and 32.
+    pub fn new(k: usize, canonical: bool) -> Result<KmerCounts, Box<dyn std::error::Error>> {
+        if k == 0 || k > MAXIMUM_K {
+            return Err(Box::new(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("k must be between 1 and {MAXIMUM_K}, not {k}"),
+            )));
+        }
+        Ok(KmerCounts {
+            k,
+            canonical,
+            counts: std::collections::HashMap::new(),
+        })
+    }
+
+    pub fn get_k(&self) -> usize {
+        self.k
+    }
+
+    pub fn is_canonical(&self) -> bool {
+        self.canonical
+    }
+
+    /// Count the k-mers of a DNA sequence.
+    pub fn add(&mut self, dna: &super::nucleotide::DnaSequence) {
+        let mask: u64 = u64::MAX >> (64 - 2 * self.k);
+        let shift: usize = 2 * (self.k - 1);
+        let mut kmer: u64 = 0;
+        let mut reverse_complement: u64 = 0;
+        // The number of unambiguous nucleotides read since the last ambiguous nucleotide.
+        let mut length: usize = 0;
+
+        for nucleotide in dna.iter() {
+            let Some(bits) = nucleotide.to_bits() else {
+                length = 0;
+                continue;
+            };
+            kmer = ((kmer << 2) | u64::from(bits)) & mask;
+            reverse_complement = (reverse_complement >> 2) | (u64::from(3 - bits) << shift);
+            length += 1;
+            if length >= self.k {
+                let key: u64 = match self.canonical {
+                    true => kmer.min(reverse_complement),
+                    false => kmer,
+                };
+                *self.counts.entry(key).or_insert(0) += 1;
+            }
+        }
+    }
+
+    /// Return the count of a k-mer, or of its canonical k-mer when counting canonical k-mers.
+    pub fn get(&self, kmer: &str) -> u64 {
+        let Ok(dna) = kmer.parse::<super::nucleotide::DnaSequence>() else {
+            return 0;
+        };
+        if dna.len() != self.k {
+            return 0;
+        }
+        let mut key: u64 = 0;
+        let mut reverse_complement: u64 = 0;
+        for (index, nucleotide) in dna.iter().enumerate() {
+            let Some(bits) = nucleotide.to_bits() else {
+                return 0;
+            };
+            key = (key << 2) | u64::from(bits);
+            reverse_complement |= u64::from(3 - bits) << (2 * index);
+        }
+        if self.canonical {
+            key = key.min(reverse_complement);
+        }
+        self.counts.get(&key).copied().unwrap_or(0)
+    }
+
+    /// The number of distinct k-mers counted.
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// The number of k-mers counted.
+    pub fn get_total(&self) -> u64 {
+        self.counts.values().sum()
+    }
+
+    /// Return each k-mer and its count, ordered by k-mer.
+    pub fn get_counts(&self) -> Vec<(String, u64)> {
+        let mut counts: Vec<(u64, u64)> = self.counts.iter().map(|(&kmer, &count)| (kmer, count)).collect();
+        counts.sort();
+        counts
+            .into_iter()
+            .map(|(kmer, count)| (self.decode(kmer), count))
+            .collect()
+    }
+
+    /// Return the k-mer spectrum: each count paired with the number of distinct k-mers occurring that many times,
+    /// ordered by count.
+    pub fn get_spectrum(&self) -> Vec<(u64, u64)> {
+        let mut spectrum: std::collections::BTreeMap<u64, u64> = std::collections::BTreeMap::new();
+        for &count in self.counts.values() {
+            *spectrum.entry(count).or_insert(0) += 1;
+        }
+        spectrum.into_iter().collect()
+    }
+
+    /// Write each k-mer and its count as CSV rows with a `kmer,count` header.
+    pub fn write_csv<W: std::io::Write>(&self, writer: W) -> Result<(), Box<dyn std::error::Error>> {
+        let mut csv_writer: csv::Writer<W> = csv::Writer::from_writer(writer);
+        csv_writer.write_record(["kmer", "count"])?;
+        for (kmer, count) in self.get_counts() {
+            csv_writer.write_record([kmer, count.to_string()])?;
+        }
+        csv_writer.flush()?;
+        Ok(())
+    }
+
+    /// Write the k-mer spectrum as CSV rows with a `count,kmers` header.
+    pub fn write_spectrum_csv<W: std::io::Write>(&self, writer: W) -> Result<(), Box<dyn std::error::Error>> {
+        let mut csv_writer: csv::Writer<W> = csv::Writer::from_writer(writer);
+        csv_writer.write_record(["count", "kmers"])?;
+        for (count, kmers) in self.get_spectrum() {
+            csv_writer.write_record([count.to_string(), kmers.to_string()])?;
+        }
+        csv_writer.flush()?;
+        Ok(())
+    }
+
+    pub fn to_json(&self) -> Result<String, Box<dyn std::error::Error>> {
+        Ok(serde_json::to_string(&self)?)
+    }
+
+    fn decode(&self, kmer: u64) -> String {
+        (0..self.k)
+            .rev()
+            .map(|index| ['A', 'C', 'G', 'T'][((kmer >> (2 * index)) & 0b11) as usize])
+            .collect()
+    }
+}
+
+impl serde::ser::Serialize for KmerCounts {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        let counts: std::collections::BTreeMap<String, u64> = self.get_counts().into_iter().collect();
+        let spectrum: std::collections::BTreeMap<u64, u64> = self.get_spectrum().into_iter().collect();
+        let mut serialized_counts = serializer.serialize_struct("KmerCounts", 4)?;
+        serialized_counts.serialize_field("k", &self.k)?;
+        serialized_counts.serialize_field("canonical", &self.canonical)?;
+        serialized_counts.serialize_field("counts", &counts)?;
+        serialized_counts.serialize_field("spectrum", &spectrum)?;
+        serialized_counts.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_on_invalid_k() {
+        assert!(KmerCounts::new(0, false).is_err());
+        assert!(KmerCounts::new(33, false).is_err());
+    }
+
+    #[test]
+    fn test_add_on_maximum_k() {
+        let dna: crate::seq::nucleotide::DnaSequence = "ACGTACGTACGTACGTACGTACGTACGTACGTA".parse().unwrap();
+        let mut counts: KmerCounts = KmerCounts::new(MAXIMUM_K, true).unwrap();
+        counts.add(&dna);
+        assert_eq!(counts.get_total(), 2);
+        assert_eq!(counts.get("ACGTACGTACGTACGTACGTACGTACGTACGT"), 1);
+        assert_eq!(counts.get("CGTACGTACGTACGTACGTACGTACGTACGTA"), 1);
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let dna: crate::seq::nucleotide::DnaSequence = "AAAC".parse().unwrap();
+        let mut counts: KmerCounts = KmerCounts::new(2, false).unwrap();
+        counts.add(&dna);
+        let mut buffer: Vec<u8> = Vec::new();
+        counts.write_csv(&mut buffer).unwrap();
+        assert_eq!(String::from_utf8(buffer).unwrap(), "kmer,count\nAA,2\nAC,1\n");
+        assert_eq!(counts.to_json().unwrap(), "{\"k\":2,\"canonical\":false,\"counts\":{\"AA\":2,\"AC\":1},\"spectrum\":{\"1\":1,\"2\":1}}");
+    }
+}
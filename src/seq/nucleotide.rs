@@ -0,0 +1,436 @@
+//! MIT license.
+
+/// An IUPAC nucleotide code.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum Nucleotide {
+    A,
+    C,
+    G,
+    T,
+    /// A or G (purine).
+    R,
+    /// C or T (pyrimidine).
+    Y,
+    /// G or C.
+    S,
+    /// A or T.
+    W,
+    /// G or T.
+    K,
+    /// A or C.
+    M,
+    /// C, G or T.
+    B,
+    /// A, G or T.
+    D,
+    /// A, C or T.
+    H,
+    /// A, C or G.
+    V,
+    /// Any base.
+    N,
+}
+
+impl Nucleotide {
+    /// The upper-case IUPAC character of the nucleotide.
+    pub fn to_char(self) -> char {
+        match self {
+            Nucleotide::A => 'A',
+            Nucleotide::C => 'C',
+            Nucleotide::G => 'G',
+            Nucleotide::T => 'T',
+            Nucleotide::R => 'R',
+            Nucleotide::Y => 'Y',
+            Nucleotide::S => 'S',
+            Nucleotide::W => 'W',
+            Nucleotide::K => 'K',
+            Nucleotide::M => 'M',
+            Nucleotide::B => 'B',
+            Nucleotide::D => 'D',
+            Nucleotide::H => 'H',
+            Nucleotide::V => 'V',
+            Nucleotide::N => 'N',
+        }
+    }
+
+    /// The nucleotide pairing with this nucleotide on the opposite strand.
+    pub fn complement(self) -> Nucleotide {
+        match self {
+            Nucleotide::A => Nucleotide::T,
+            Nucleotide::C => Nucleotide::G,
+            Nucleotide::G => Nucleotide::C,
+            Nucleotide::T => Nucleotide::A,
+            Nucleotide::R => Nucleotide::Y,
+            Nucleotide::Y => Nucleotide::R,
+            Nucleotide::K => Nucleotide::M,
+            Nucleotide::M => Nucleotide::K,
+            Nucleotide::B => Nucleotide::V,
+            Nucleotide::V => Nucleotide::B,
+            Nucleotide::D => Nucleotide::H,
+            Nucleotide::H => Nucleotide::D,
+            nucleotide => nucleotide,
+        }
+    }
+
+    /// The bases (A, C, G or T) the nucleotide code stands for.
+    pub fn get_bases(self) -> &'static [Nucleotide] {
+        match self {
+            Nucleotide::A => &[Nucleotide::A],
+            Nucleotide::C => &[Nucleotide::C],
+            Nucleotide::G => &[Nucleotide::G],
+            Nucleotide::T => &[Nucleotide::T],
+            Nucleotide::R => &[Nucleotide::A, Nucleotide::G],
+            Nucleotide::Y => &[Nucleotide::C, Nucleotide::T],
+            Nucleotide::S => &[Nucleotide::C, Nucleotide::G],
+            Nucleotide::W => &[Nucleotide::A, Nucleotide::T],
+            Nucleotide::K => &[Nucleotide::G, Nucleotide::T],
+            Nucleotide::M => &[Nucleotide::A, Nucleotide::C],
+            Nucleotide::B => &[Nucleotide::C, Nucleotide::G, Nucleotide::T],
+            Nucleotide::D => &[Nucleotide::A, Nucleotide::G, Nucleotide::T],
+            Nucleotide::H => &[Nucleotide::A, Nucleotide::C, Nucleotide::T],
+            Nucleotide::V => &[Nucleotide::A, Nucleotide::C, Nucleotide::G],
+            Nucleotide::N => &[Nucleotide::A, Nucleotide::C, Nucleotide::G, Nucleotide::T],
+        }
+    }
+
+    /// Whether the nucleotide code stands for more than one base.
+    pub fn is_ambiguous(self) -> bool {
+        self.get_bases().len() > 1
+    }
+
+    /// The 2-bit code of an unambiguous base: A = 0, C = 1, G = 2 and T = 3.
+    pub fn to_bits(self) -> Option<u8> {
+        match self {
+            Nucleotide::A => Some(0),
+            Nucleotide::C => Some(1),
+            Nucleotide::G => Some(2),
+            Nucleotide::T => Some(3),
+            _ => None,
+        }
+    }
+
+    fn from_bits(bits: u8) -> Nucleotide {
+        match bits & 0b11 {
+            0 => Nucleotide::A,
+            1 => Nucleotide::C,
+            2 => Nucleotide::G,
+            _ => Nucleotide::T,
+        }
+    }
+}
+
+/// Parse a nucleotide from its IUPAC character in either case.
+impl TryFrom<char> for Nucleotide {
+    type Error = NucleotideError;
+
+    fn try_from(character: char) -> Result<Self, Self::Error> {
+        match character.to_ascii_uppercase() {
+            'A' => Ok(Nucleotide::A),
+            'C' => Ok(Nucleotide::C),
+            'G' => Ok(Nucleotide::G),
+            'T' => Ok(Nucleotide::T),
+            'R' => Ok(Nucleotide::R),
+            'Y' => Ok(Nucleotide::Y),
+            'S' => Ok(Nucleotide::S),
+            'W' => Ok(Nucleotide::W),
+            'K' => Ok(Nucleotide::K),
+            'M' => Ok(Nucleotide::M),
+            'B' => Ok(Nucleotide::B),
+            'D' => Ok(Nucleotide::D),
+            'H' => Ok(Nucleotide::H),
+            'V' => Ok(Nucleotide::V),
+            'N' => Ok(Nucleotide::N),
+            _ => Err(NucleotideError {
+                character,
+                position: 0,
+            }),
+        }
+    }
+}
+
+impl std::fmt::Display for Nucleotide {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+/// A character that is not an IUPAC nucleotide code.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct NucleotideError {
+    character: char,
+    position: usize,
+}
+
+impl NucleotideError {
+    pub fn get_character(&self) -> char {
+        self.character
+    }
+
+    /// The index of the character in the sequence being parsed.
+    pub fn get_position(&self) -> usize {
+        self.position
+    }
+}
+
+impl std::fmt::Display for NucleotideError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid nucleotide '{}' at position {}",
+            self.character.escape_default(),
+            self.position
+        )
+    }
+}
+
+impl std::error::Error for NucleotideError {}
+
+/// A DNA sequence stored with 2 bits per base.
+///
+/// Ambiguous IUPAC codes are kept as runs alongside the packed bases, and the case of each base is kept
+/// as runs of soft-masked (lower-case) bases, so that the sequence is displayed exactly as it was parsed.
+///
+/// # Example
+///
+/// ```
+/// let dna: utils::seq::nucleotide::DnaSequence = "ACGTnnnnacgtRY".parse().unwrap();
+/// assert_eq!(dna.len(), 14);
+/// assert_eq!(dna.get(4), Some(utils::seq::nucleotide::Nucleotide::N));
+/// assert!(dna.is_soft_masked(4));
+/// assert_eq!(dna.get_soft_masked(), &[utils::seq::Sequence::new(4, 12)]);
+/// assert_eq!(dna.to_string(), "ACGTnnnnacgtRY");
+/// assert_eq!(dna.to_unmasked_string(), "ACGTNNNNACGTRY");
+/// assert_eq!(dna.reverse_complement().to_string(), "RYacgtnnnnACGT");
+///
+/// let error = "ACGU".parse::<utils::seq::nucleotide::DnaSequence>().unwrap_err();
+/// assert_eq!(error.to_string(), "invalid nucleotide 'U' at position 3");
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct DnaSequence {
+    length: usize,
+    packed: Vec<u8>,
+    ambiguous: Vec<(super::Sequence, Nucleotide)>,
+    soft_masked: Vec<super::Sequence>,
+}
+
+impl DnaSequence {
+    pub fn new() -> DnaSequence {
+        DnaSequence::default()
+    }
+
+    /// Append a nucleotide, soft-masked when `soft_masked` is true.
+    pub fn push(&mut self, nucleotide: Nucleotide, soft_masked: bool) {
+        let position: usize = self.length;
+        if position.is_multiple_of(4) {
+            self.packed.push(0);
+        }
+        match nucleotide.to_bits() {
+            Some(bits) => self.packed[position / 4] |= bits << ((position % 4) * 2),
+            // Extend the last run of ambiguous nucleotides when it is adjacent and of the same code.
+            None => match self.ambiguous.last_mut() {
+                Some((run, code)) if run.end == position && *code == nucleotide => run.end += 1,
+                _ => self.ambiguous.push((super::Sequence::new(position, position + 1), nucleotide)),
+            },
+        }
+        if soft_masked {
+            match self.soft_masked.last_mut() {
+                Some(run) if run.end == position => run.end += 1,
+                _ => self.soft_masked.push(super::Sequence::new(position, position + 1)),
+            }
+        }
+        self.length += 1;
+    }
+
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Return the nucleotide at `index`.
+    pub fn get(&self, index: usize) -> Option<Nucleotide> {
+        if index >= self.length {
+            return None;
+        }
+        match self.ambiguous.binary_search_by(|(run, _)| compare_run(run, index)) {
+            Ok(found) => Some(self.ambiguous[found].1),
+            Err(_) => Some(Nucleotide::from_bits(self.packed[index / 4] >> ((index % 4) * 2))),
+        }
+    }
+
+    /// Whether the nucleotide at `index` is soft-masked.
+    pub fn is_soft_masked(&self, index: usize) -> bool {
+        self.soft_masked
+            .binary_search_by(|run| compare_run(run, index))
+            .is_ok()
+    }
+
+    /// The runs of soft-masked nucleotides.
+    pub fn get_soft_masked(&self) -> &[super::Sequence] {
+        &self.soft_masked
+    }
+
+    /// The runs of ambiguous nucleotides and their codes.
+    pub fn get_ambiguous(&self) -> &[(super::Sequence, Nucleotide)] {
+        &self.ambiguous
+    }
+
+    /// The unambiguous bases packed four to a byte, the first base in the lowest 2 bits.
+    /// Ambiguous nucleotides are packed as A.
+    pub fn get_packed(&self) -> &[u8] {
+        &self.packed
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Nucleotide> + '_ {
+        (0..self.length).map(|index| self.get(index).unwrap_or(Nucleotide::N))
+    }
+
+    /// Return the reverse complement, keeping the soft-masking of each nucleotide.
+    pub fn reverse_complement(&self) -> DnaSequence {
+        let mut reverse_complement: DnaSequence = DnaSequence::new();
+        for index in (0..self.length).rev() {
+            let nucleotide: Nucleotide = self.get(index).unwrap_or(Nucleotide::N);
+            reverse_complement.push(nucleotide.complement(), self.is_soft_masked(index));
+        }
+        reverse_complement
+    }
+
+    /// Return the nucleotides from `start` up to `end`.
+    pub fn slice(&self, start: usize, end: usize) -> DnaSequence {
+        let mut slice: DnaSequence = DnaSequence::new();
+        for index in start..end.min(self.length) {
+            slice.push(self.get(index).unwrap_or(Nucleotide::N), self.is_soft_masked(index));
+        }
+        slice
+    }
+
+    /// Return the sequence as upper-case IUPAC characters, ignoring soft-masking.
+    pub fn to_unmasked_string(&self) -> String {
+        self.iter().map(Nucleotide::to_char).collect()
+    }
+
+    /// Return the sequence as upper-case IUPAC codes, one byte per nucleotide, unpacking four bases from each
+    /// packed byte and writing the runs of ambiguous nucleotides over them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let dna: utils::seq::nucleotide::DnaSequence = "ACGtNNa".parse().unwrap();
+    /// assert_eq!(dna.to_unmasked_bytes(), b"ACGTNNA");
+    /// ```
+    pub fn to_unmasked_bytes(&self) -> Vec<u8> {
+        let mut bytes: Vec<u8> = self
+            .packed
+            .iter()
+            .flat_map(|&byte| (0..4).map(move |index| Nucleotide::from_bits(byte >> (index * 2)).to_char() as u8))
+            .take(self.length)
+            .collect();
+        for (run, code) in self.ambiguous.iter() {
+            bytes[run.get_start()..run.get_end()].fill(code.to_char() as u8);
+        }
+        bytes
+    }
+}
+
+impl std::str::FromStr for DnaSequence {
+    type Err = NucleotideError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut dna: DnaSequence = DnaSequence::new();
+        for (position, character) in string.chars().enumerate() {
+            match Nucleotide::try_from(character) {
+                Ok(nucleotide) => dna.push(nucleotide, character.is_ascii_lowercase()),
+                Err(error) => {
+                    return Err(NucleotideError {
+                        position,
+                        ..error
+                    })
+                }
+            }
+        }
+        Ok(dna)
+    }
+}
+
+impl std::fmt::Display for DnaSequence {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut text: String = self.to_unmasked_string();
+        for run in self.soft_masked.iter() {
+            text[run.get_start()..run.get_end()].make_ascii_lowercase();
+        }
+        write!(f, "{text}")
+    }
+}
+
+impl serde::ser::Serialize for DnaSequence {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+/// Parse a DNA sequence from a string, such as a gene's `dna` column, failing on a character that is not an IUPAC nucleotide code.
+impl<'de> serde::de::Deserialize<'de> for DnaSequence {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let string: String = serde::de::Deserialize::deserialize(deserializer)?;
+        string.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Order a run of positions relative to `index`, finding the run containing `index` when searched.
+fn compare_run(run: &super::Sequence, index: usize) -> std::cmp::Ordering {
+    if run.end <= index {
+        std::cmp::Ordering::Less
+    } else if run.start > index {
+        std::cmp::Ordering::Greater
+    } else {
+        std::cmp::Ordering::Equal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_packs_four_bases_per_byte() {
+        let dna: DnaSequence = "ACGTTGCA".parse().unwrap();
+        assert_eq!(dna.get_packed(), &[0b11_10_01_00, 0b00_01_10_11]);
+        assert!(dna.get_ambiguous().is_empty());
+        assert!(dna.get_soft_masked().is_empty());
+    }
+
+    #[test]
+    fn test_parse_keeps_ambiguous_runs() {
+        let dna: DnaSequence = "ANNNRYN".parse().unwrap();
+        let expected: Vec<(crate::seq::Sequence, Nucleotide)> = vec![
+            (crate::seq::Sequence::new(1, 4), Nucleotide::N),
+            (crate::seq::Sequence::new(4, 5), Nucleotide::R),
+            (crate::seq::Sequence::new(5, 6), Nucleotide::Y),
+            (crate::seq::Sequence::new(6, 7), Nucleotide::N),
+        ];
+        assert_eq!(dna.get_ambiguous(), expected.as_slice());
+        assert_eq!(dna.to_string(), "ANNNRYN");
+    }
+
+    #[test]
+    fn test_parse_on_multibyte_character() {
+        let error: NucleotideError = "ACGé".parse::<DnaSequence>().unwrap_err();
+        assert_eq!(error.get_character(), 'é');
+        assert_eq!(error.get_position(), 3);
+    }
+
+    #[test]
+    fn test_slice_keeps_soft_masking() {
+        let dna: DnaSequence = "ACgtaCGT".parse().unwrap();
+        assert_eq!(dna.slice(1, 6).to_string(), "CgtaC");
+        assert_eq!(dna.slice(6, 20).to_string(), "GT");
+    }
+}
@@ -0,0 +1,188 @@
+//! MIT license.
+
+use serde::ser::SerializeStruct;
+
+/// A repeated sub-string and the positions of each of its occurrences.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Repeat {
+    sequence: String,
+    occurrences: Vec<super::Sequence>,
+}
+
+impl Repeat {
+    pub fn new(sequence: &str, occurrences: Vec<super::Sequence>) -> Repeat {
+        Repeat {
+            sequence: String::from(sequence),
+            occurrences,
+        }
+    }
+
+    pub fn get_sequence(&self) -> &str {
+        &self.sequence
+    }
+
+    pub fn get_occurrences(&self) -> &[super::Sequence] {
+        &self.occurrences
+    }
+
+    pub fn to_json(&self) -> Result<String, Box<dyn std::error::Error>> {
+        Ok(serde_json::to_string(&self)?)
+    }
+}
+
+impl serde::ser::Serialize for Repeat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        let mut serialized_repeat = serializer.serialize_struct("Repeat", 2)?;
+        serialized_repeat.serialize_field("sequence", &self.sequence)?;
+        serialized_repeat.serialize_field("occurrences", &self.occurrences)?;
+        serialized_repeat.end()
+    }
+}
+
+/// The kind of repeats to enumerate from a suffix array.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RepeatKind {
+    /// Repeats that cannot be extended to the left or to the right without losing an occurrence.
+    Maximal,
+    /// Maximal repeats that do not occur as a sub-string of any other maximal repeat.
+    Supermaximal,
+}
+
+/// An lcp-interval: the range of suffixes `[left, right]` sharing a common prefix of `lcp` bytes.
+struct Interval {
+    lcp: usize,
+    left: usize,
+    has_child: bool,
+}
+
+/// Enumerate the maximal or supermaximal repeats of an indexed text with at least `minimum_length` bytes
+/// and `minimum_count` occurrences, ordered lexicographically.
+/// When both strands are indexed, occurrences read from the reverse complement are reported on the reverse strand.
+///
+/// Every right-maximal repeat is the common prefix of an lcp-interval of the suffix array. The repeat is
+/// also left-maximal when the characters preceding its occurrences differ (or an occurrence starts the text),
+/// and supermaximal when the interval has no child intervals and every preceding character is distinct.
+pub fn find_repeats(
+    sequences: &super::Sequences,
+    kind: RepeatKind,
+    minimum_length: usize,
+    minimum_count: usize,
+) -> Vec<Repeat> {
+    let index: &super::suffix_array::SuffixArray = &sequences.index;
+    let text: &[u8] = index.get_text();
+    let suffixes: &[usize] = index.get_suffixes();
+    let lcp: &[usize] = index.get_lcp();
+
+    // The character preceding each suffix, or `None` for the suffix starting the text.
+    let preceding: Vec<Option<u8>> = suffixes
+        .iter()
+        .map(|&suffix| suffix.checked_sub(1).map(|position| text[position]))
+        .collect();
+    // Count the suffixes up to each rank that start the text or whose preceding character differs from the previous suffix,
+    // so that any interval can be tested for left diversity in constant time.
+    let mut diverse: Vec<usize> = vec![0; suffixes.len() + 1];
+    for rank in 0..suffixes.len() {
+        let is_diverse: bool =
+            preceding[rank].is_none() || (rank > 0 && preceding[rank] != preceding[rank - 1]);
+        diverse[rank + 1] = diverse[rank] + usize::from(is_diverse);
+    }
+
+    let mut repeats: Vec<Repeat> = Vec::new();
+    let mut report = |interval: &Interval, right: usize| {
+        let count: usize = right - interval.left + 1;
+        if interval.lcp < minimum_length.max(1) || count < minimum_count {
+            return;
+        }
+        let is_repeat: bool = match kind {
+            RepeatKind::Maximal => {
+                preceding[interval.left].is_none()
+                    || diverse[right + 1] - diverse[interval.left + 1] > 0
+            }
+            RepeatKind::Supermaximal => {
+                let mut characters: Vec<Option<u8>> = preceding[interval.left..=right].to_vec();
+                characters.sort();
+                characters.dedup();
+                !interval.has_child && characters.len() == count
+            }
+        };
+        if !is_repeat {
+            return;
+        }
+        let start: usize = suffixes[interval.left];
+        if let Ok(sequence) = std::str::from_utf8(&text[start..start + interval.lcp]) {
+            let mut occurrences: Vec<super::Sequence> = suffixes[interval.left..=right]
+                .iter()
+                .map(|&suffix| sequences.locate(suffix, interval.lcp))
+                .collect();
+            occurrences.sort();
+            repeats.push(Repeat::new(sequence, occurrences));
+        }
+    };
+
+    // Traverse the lcp-intervals bottom-up with a stack.
+    let mut stack: Vec<Interval> = vec![Interval {
+        lcp: 0,
+        left: 0,
+        has_child: false,
+    }];
+    for rank in 1..=suffixes.len() {
+        let current: usize = lcp.get(rank).copied().unwrap_or(0);
+        let mut left: usize = rank - 1;
+        let mut has_child: bool = false;
+        while stack.last().is_some_and(|top| current < top.lcp) {
+            let interval: Interval = stack.pop().unwrap();
+            report(&interval, rank - 1);
+            left = interval.left;
+            match stack.last_mut() {
+                Some(top) if current <= top.lcp => top.has_child = true,
+                _ => has_child = true,
+            }
+        }
+        if stack.last().is_none_or(|top| current > top.lcp) {
+            stack.push(Interval {
+                lcp: current,
+                left,
+                has_child,
+            });
+        }
+    }
+
+    repeats.sort_by(|a, b| a.sequence.cmp(&b.sequence));
+    repeats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_repeats_on_overlapping_repeats() {
+        let sequences: crate::seq::Sequences = crate::seq::Sequences::from(String::from("ATATAT"));
+        let repeats: Vec<Repeat> = find_repeats(&sequences, RepeatKind::Maximal, 2, 2);
+        let result: Vec<&str> = repeats.iter().map(|repeat| repeat.get_sequence()).collect();
+        assert_eq!(result, vec!["AT", "ATAT"]);
+        assert_eq!(repeats[1].get_occurrences(), &[crate::seq::Sequence::new(0, 4), crate::seq::Sequence::new(2, 6)]);
+    }
+
+    #[test]
+    fn test_find_repeats_on_single_character_alphabet() {
+        let sequences: crate::seq::Sequences = crate::seq::Sequences::from(String::from("AAAAA"));
+        let maximal: Vec<Repeat> = find_repeats(&sequences, RepeatKind::Maximal, 1, 2);
+        let result: Vec<&str> = maximal.iter().map(|repeat| repeat.get_sequence()).collect();
+        assert_eq!(result, vec!["A", "AA", "AAA", "AAAA"]);
+        assert_eq!(maximal[0].get_occurrences().len(), 5);
+        let supermaximal: Vec<Repeat> = find_repeats(&sequences, RepeatKind::Supermaximal, 1, 2);
+        let result: Vec<&str> = supermaximal.iter().map(|repeat| repeat.get_sequence()).collect();
+        assert_eq!(result, vec!["AAAA"]);
+    }
+
+    #[test]
+    fn test_find_repeats_on_empty_string() {
+        let sequences: crate::seq::Sequences = crate::seq::Sequences::from(String::new());
+        assert!(find_repeats(&sequences, RepeatKind::Maximal, 1, 2).is_empty());
+        assert!(find_repeats(&sequences, RepeatKind::Supermaximal, 1, 2).is_empty());
+    }
+}
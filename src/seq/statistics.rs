@@ -0,0 +1,172 @@
+//! MIT license.
+
+use serde::ser::SerializeStruct;
+
+/// Composition metrics of a DNA sequence.
+///
+/// Fractions are of the whole sequence, except GC content, which is of the unambiguous (A, C, G, T) nucleotides.
+/// Ratios with a zero denominator are zero.
+///
+/// # Example
+///
+/// ```
+/// let statistics = utils::seq::statistics::SequenceStatistics::new("ACGCGNNaaaaT");
+/// assert_eq!(statistics.get_length(), 12);
+/// assert_eq!(statistics.get_gc_content(), 0.4);
+/// assert_eq!(statistics.get_gc_skew(), 0.0);
+/// assert_eq!(statistics.get_cpg_observed_expected(), 6.0);
+/// assert_eq!(statistics.get_n_content(), 2.0 / 12.0);
+/// assert_eq!(statistics.get_soft_masked_fraction(), 4.0 / 12.0);
+/// assert_eq!(statistics.get_longest_homopolymer(), 4);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SequenceStatistics {
+    length: usize,
+    gc_content: f64,
+    gc_skew: f64,
+    cpg_observed_expected: f64,
+    n_content: f64,
+    soft_masked_fraction: f64,
+    longest_homopolymer: usize,
+}
+
+impl SequenceStatistics {
+    /// Compute the statistics of a DNA sequence, ignoring case except to find soft-masked (lowercase) nucleotides.
+    pub fn new(dna: &str) -> SequenceStatistics {
+        let mut counts: [usize; 4] = [0; 4];
+        let mut cpg: usize = 0;
+        let mut n: usize = 0;
+        let mut soft_masked: usize = 0;
+        let mut longest_homopolymer: usize = 0;
+        let mut homopolymer: usize = 0;
+        let mut previous: Option<u8> = None;
+
+        for &byte in dna.as_bytes() {
+            let nucleotide: u8 = byte.to_ascii_uppercase();
+            match nucleotide {
+                b'A' => counts[0] += 1,
+                b'C' => counts[1] += 1,
+                b'G' => counts[2] += 1,
+                b'T' => counts[3] += 1,
+                b'N' => n += 1,
+                _ => {}
+            }
+            if matches!(byte, b'a' | b'c' | b'g' | b't') {
+                soft_masked += 1;
+            }
+            if previous == Some(b'C') && nucleotide == b'G' {
+                cpg += 1;
+            }
+
+            // Runs of unknown nucleotides are not homopolymers.
+            homopolymer = match (previous == Some(nucleotide), nucleotide) {
+                (_, b'N') => 0,
+                (true, _) => homopolymer + 1,
+                (false, _) => 1,
+            };
+            longest_homopolymer = longest_homopolymer.max(homopolymer);
+            previous = Some(nucleotide);
+        }
+
+        let length: usize = dna.len();
+        let [a, c, g, t] = counts;
+        SequenceStatistics {
+            length,
+            gc_content: ratio((g + c) as f64, (a + c + g + t) as f64),
+            gc_skew: ratio(g as f64 - c as f64, (g + c) as f64),
+            cpg_observed_expected: ratio((cpg * length) as f64, (c * g) as f64),
+            n_content: ratio(n as f64, length as f64),
+            soft_masked_fraction: ratio(soft_masked as f64, length as f64),
+            longest_homopolymer,
+        }
+    }
+
+    pub fn get_length(&self) -> usize {
+        self.length
+    }
+
+    /// The fraction of unambiguous nucleotides that are G or C.
+    pub fn get_gc_content(&self) -> f64 {
+        self.gc_content
+    }
+
+    /// (G - C) / (G + C), between -1 and 1.
+    pub fn get_gc_skew(&self) -> f64 {
+        self.gc_skew
+    }
+
+    /// The number of CpG dinucleotides relative to the number expected from the C and G content: CpG × length / (C × G).
+    pub fn get_cpg_observed_expected(&self) -> f64 {
+        self.cpg_observed_expected
+    }
+
+    /// The fraction of the sequence that is N.
+    pub fn get_n_content(&self) -> f64 {
+        self.n_content
+    }
+
+    /// The fraction of the sequence that is soft-masked, i.e. lowercase interspersed repeats.
+    pub fn get_soft_masked_fraction(&self) -> f64 {
+        self.soft_masked_fraction
+    }
+
+    /// The length of the longest run of a single nucleotide.
+    pub fn get_longest_homopolymer(&self) -> usize {
+        self.longest_homopolymer
+    }
+
+    pub fn to_json(&self) -> Result<String, Box<dyn std::error::Error>> {
+        Ok(serde_json::to_string(&self)?)
+    }
+
+    /// Serialize the statistics as fields of an enclosing struct, such as a CSV row describing a gene.
+    pub fn serialize_fields<S>(&self, serialized_struct: &mut S) -> Result<(), S::Error>
+    where
+        S: serde::ser::SerializeStruct,
+    {
+        serialized_struct.serialize_field("length", &self.length)?;
+        serialized_struct.serialize_field("gc_content", &self.gc_content)?;
+        serialized_struct.serialize_field("gc_skew", &self.gc_skew)?;
+        serialized_struct.serialize_field("cpg_observed_expected", &self.cpg_observed_expected)?;
+        serialized_struct.serialize_field("n_content", &self.n_content)?;
+        serialized_struct.serialize_field("soft_masked_fraction", &self.soft_masked_fraction)?;
+        serialized_struct.serialize_field("longest_homopolymer", &self.longest_homopolymer)?;
+        Ok(())
+    }
+}
+
+impl serde::ser::Serialize for SequenceStatistics {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        let mut serialized_statistics = serializer.serialize_struct("SequenceStatistics", 7)?;
+        self.serialize_fields(&mut serialized_statistics)?;
+        serialized_statistics.end()
+    }
+}
+
+fn ratio(numerator: f64, denominator: f64) -> f64 {
+    match denominator == 0.0 {
+        true => 0.0,
+        false => numerator / denominator,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_on_empty_sequence() {
+        assert_eq!(SequenceStatistics::new(""), SequenceStatistics::default());
+    }
+
+    #[test]
+    fn test_new_on_mixed_case_homopolymer() {
+        let statistics: SequenceStatistics = SequenceStatistics::new("CGGGggTNNNNNA");
+        assert_eq!(statistics.get_longest_homopolymer(), 5);
+        assert_eq!(statistics.get_gc_skew(), 4.0 / 6.0);
+        assert_eq!(statistics.get_soft_masked_fraction(), 2.0 / 13.0);
+    }
+}
@@ -0,0 +1,194 @@
+//! MIT license.
+
+/// A suffix array and its longest common prefix (LCP) array built over a sequence of bytes.
+///
+/// The suffix array is constructed by prefix doubling with radix sorting in `O(n log n)` time
+/// and the LCP array with Kasai's algorithm in `O(n)` time; both use `O(n)` memory.
+///
+/// # Example
+///
+/// ```
+/// let suffix_array = utils::seq::suffix_array::SuffixArray::new(b"banana".to_vec());
+/// assert_eq!(suffix_array.get_suffixes(), &[5, 3, 1, 0, 4, 2]);
+/// assert_eq!(suffix_array.get_lcp(), &[0, 1, 3, 0, 0, 2]);
+/// assert_eq!(suffix_array.find(b"ana"), 1..3);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SuffixArray {
+    text: Vec<u8>,
+    suffixes: Vec<usize>,
+    lcp: Vec<usize>,
+}
+
+impl SuffixArray {
+    pub fn new(text: Vec<u8>) -> SuffixArray {
+        let suffixes: Vec<usize> = build_suffixes(&text);
+        let lcp: Vec<usize> = build_lcp(&text, &suffixes);
+        SuffixArray {
+            text,
+            suffixes,
+            lcp,
+        }
+    }
+
+    /// The indexed text.
+    pub fn get_text(&self) -> &[u8] {
+        &self.text
+    }
+
+    /// The starting positions of every suffix of the text in lexicographical order.
+    pub fn get_suffixes(&self) -> &[usize] {
+        &self.suffixes
+    }
+
+    /// The length of the longest common prefix shared by each suffix and the suffix preceding it
+    /// in the suffix array. The first entry is always zero.
+    pub fn get_lcp(&self) -> &[usize] {
+        &self.lcp
+    }
+
+    pub fn len(&self) -> usize {
+        self.text.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Return the range of the suffix array holding the suffixes prefixed by `pattern`.
+    pub fn find(&self, pattern: &[u8]) -> std::ops::Range<usize> {
+        let start: usize = self
+            .suffixes
+            .partition_point(|&suffix| &self.text[suffix..] < pattern);
+        let end: usize = start
+            + self.suffixes[start..].partition_point(|&suffix| {
+                let suffix: &[u8] = &self.text[suffix..];
+                &suffix[..pattern.len().min(suffix.len())] <= pattern
+            });
+        start..end
+    }
+}
+
+/// Sort the suffixes of `text` by prefix doubling.
+///
+/// Each round sorts the suffixes by the pair of ranks of their first `k` and following `k` bytes
+/// using two stable counting sorts, doubling `k` until every rank is unique.
+fn build_suffixes(text: &[u8]) -> Vec<usize> {
+    let length: usize = text.len();
+    if length == 0 {
+        return Vec::new();
+    }
+
+    // Rank each suffix by its first byte.
+    let mut suffixes: Vec<usize> = (0..length).collect();
+    suffixes.sort_by_key(|&suffix| text[suffix]);
+    let mut ranks: Vec<usize> = vec![0; length];
+    for index in 1..length {
+        ranks[suffixes[index]] = ranks[suffixes[index - 1]]
+            + usize::from(text[suffixes[index]] != text[suffixes[index - 1]]);
+    }
+
+    let mut buffer: Vec<usize> = vec![0; length];
+    let mut counts: Vec<usize> = vec![0; length + 1];
+    let mut k: usize = 1;
+    while ranks[suffixes[length - 1]] < length - 1 {
+        // Order the suffixes by their second key: suffixes without a second half come first,
+        // followed by the remaining suffixes in the order of the rank of their second half.
+        let mut position: usize = 0;
+        for suffix in length - k..length {
+            buffer[position] = suffix;
+            position += 1;
+        }
+        for &suffix in suffixes.iter() {
+            if suffix >= k {
+                buffer[position] = suffix - k;
+                position += 1;
+            }
+        }
+
+        // Stable counting sort by the first key.
+        counts.iter_mut().for_each(|count| *count = 0);
+        for &rank in ranks.iter() {
+            counts[rank + 1] += 1;
+        }
+        for index in 1..=length {
+            counts[index] += counts[index - 1];
+        }
+        for &suffix in buffer.iter() {
+            suffixes[counts[ranks[suffix]]] = suffix;
+            counts[ranks[suffix]] += 1;
+        }
+
+        // Re-rank the suffixes by the pair of keys.
+        let key = |suffix: usize| (ranks[suffix], ranks.get(suffix + k).map(|&rank| rank + 1));
+        buffer[suffixes[0]] = 0;
+        for index in 1..length {
+            buffer[suffixes[index]] = buffer[suffixes[index - 1]]
+                + usize::from(key(suffixes[index]) != key(suffixes[index - 1]));
+        }
+        std::mem::swap(&mut ranks, &mut buffer);
+        k *= 2;
+    }
+
+    suffixes
+}
+
+/// Compute the longest common prefix array with Kasai's algorithm.
+fn build_lcp(text: &[u8], suffixes: &[usize]) -> Vec<usize> {
+    let length: usize = text.len();
+    let mut ranks: Vec<usize> = vec![0; length];
+    for (rank, &suffix) in suffixes.iter().enumerate() {
+        ranks[suffix] = rank;
+    }
+
+    let mut lcp: Vec<usize> = vec![0; length];
+    let mut common: usize = 0;
+    for suffix in 0..length {
+        if ranks[suffix] == 0 {
+            common = 0;
+            continue;
+        }
+        let previous: usize = suffixes[ranks[suffix] - 1];
+        while suffix + common < length
+            && previous + common < length
+            && text[suffix + common] == text[previous + common]
+        {
+            common += 1;
+        }
+        lcp[ranks[suffix]] = common;
+        common = common.saturating_sub(1);
+    }
+    lcp
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive_suffixes(text: &[u8]) -> Vec<usize> {
+        let mut suffixes: Vec<usize> = (0..text.len()).collect();
+        suffixes.sort_by_key(|&suffix| &text[suffix..]);
+        suffixes
+    }
+
+    #[test]
+    fn test_suffixes_match_naive_sort() {
+        for text in ["", "a", "gggg", "actgggact", "mississippi", "ACGTACGTTTGACNNACGT"] {
+            let suffix_array: SuffixArray = SuffixArray::new(text.as_bytes().to_vec());
+            assert_eq!(suffix_array.get_suffixes(), naive_suffixes(text.as_bytes()));
+        }
+    }
+
+    #[test]
+    fn test_lcp_on_repeating_character() {
+        let suffix_array: SuffixArray = SuffixArray::new(b"gggg".to_vec());
+        assert_eq!(suffix_array.get_lcp(), &[0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_find_on_missing_pattern() {
+        let suffix_array: SuffixArray = SuffixArray::new(b"actact".to_vec());
+        assert!(suffix_array.find(b"tt").is_empty());
+        assert!(suffix_array.find(b"actactact").is_empty());
+    }
+}
@@ -0,0 +1,253 @@
+//! MIT license.
+
+use serde::ser::SerializeStruct;
+
+/// The repeat unit lengths of microsatellites (short tandem repeats).
+pub const MICROSATELLITE_PERIODS: std::ops::RangeInclusive<usize> = 1..=6;
+
+/// The repeat unit lengths of minisatellites.
+pub const MINISATELLITE_PERIODS: std::ops::RangeInclusive<usize> = 7..=64;
+
+// Scores used to extend a tandem repeat: each base matching the base one period earlier adds `MATCH_SCORE`,
+// each mismatching base subtracts `MISMATCH_PENALTY`, and extension stops once the score falls `DROP_OFF` below its best.
+const MATCH_SCORE: i64 = 1;
+const MISMATCH_PENALTY: i64 = 3;
+const DROP_OFF: i64 = 7;
+
+/// A run of adjacent, possibly imperfect, copies of a repeat unit.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TandemRepeat {
+    sequence: super::Sequence,
+    period: usize,
+    motif: String,
+    copy_number: f64,
+    purity: f64,
+}
+
+impl TandemRepeat {
+    pub fn new(sequence: super::Sequence, period: usize, motif: &str, copy_number: f64, purity: f64) -> TandemRepeat {
+        TandemRepeat {
+            sequence,
+            period,
+            motif: String::from(motif),
+            copy_number,
+            purity,
+        }
+    }
+
+    /// The position of the tandem repeat.
+    pub fn get_sequence(&self) -> super::Sequence {
+        self.sequence
+    }
+
+    /// The length of the repeat unit.
+    pub fn get_period(&self) -> usize {
+        self.period
+    }
+
+    /// The consensus repeat unit, starting at the first base of the tandem repeat.
+    pub fn get_motif(&self) -> &str {
+        &self.motif
+    }
+
+    /// The length of the tandem repeat divided by its period.
+    pub fn get_copy_number(&self) -> f64 {
+        self.copy_number
+    }
+
+    /// The fraction of bases in the tandem repeat that agree with the consensus repeat unit.
+    pub fn get_purity(&self) -> f64 {
+        self.purity
+    }
+
+    /// Return the tandem repeat with its position replaced.
+    pub fn with_sequence(&self, sequence: super::Sequence) -> TandemRepeat {
+        TandemRepeat {
+            sequence,
+            ..self.clone()
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String, Box<dyn std::error::Error>> {
+        Ok(serde_json::to_string(&self)?)
+    }
+}
+
+impl serde::ser::Serialize for TandemRepeat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        let mut serialized_tandem_repeat = serializer.serialize_struct("TandemRepeat", 5)?;
+        serialized_tandem_repeat.serialize_field("sequence", &self.sequence)?;
+        serialized_tandem_repeat.serialize_field("period", &self.period)?;
+        serialized_tandem_repeat.serialize_field("motif", &self.motif)?;
+        serialized_tandem_repeat.serialize_field("copy_number", &self.copy_number)?;
+        serialized_tandem_repeat.serialize_field("purity", &self.purity)?;
+        serialized_tandem_repeat.end()
+    }
+}
+
+/// Find tandem repeats in a DNA sequence with a period in `periods`, spanning at least `minimum_length` bases
+/// and two copies of the repeat unit, whose purity is at least `minimum_purity`.
+///
+/// Bases are compared case-insensitively so soft-masked regions are searched, and `N` never matches.
+/// A tandem repeat is only reported at its shortest period: a run of `CACACACA` is reported with the motif `CA`,
+/// not `CACA`. Tandem repeats of different periods may overlap.
+///
+/// # Example
+///
+/// ```
+/// let dna: &str = "GATTCACACACACACACAGGTCTAG";
+/// let repeats = utils::seq::tandem::find_tandem_repeats(dna, utils::seq::tandem::MICROSATELLITE_PERIODS, 10, 0.9);
+/// assert_eq!(repeats.len(), 1);
+/// assert_eq!(repeats[0].get_motif(), "CA");
+/// assert_eq!(repeats[0].get_sequence(), utils::seq::Sequence::new(4, 18));
+/// assert_eq!(repeats[0].get_copy_number(), 7.0);
+/// assert_eq!(repeats[0].get_purity(), 1.0);
+/// ```
+pub fn find_tandem_repeats(
+    sequence: &str,
+    periods: std::ops::RangeInclusive<usize>,
+    minimum_length: usize,
+    minimum_purity: f64,
+) -> Vec<TandemRepeat> {
+    let bases: Vec<u8> = sequence.bytes().map(|base| base.to_ascii_uppercase()).collect();
+    let mut repeats: Vec<TandemRepeat> = Vec::new();
+
+    for period in periods.filter(|&period| period > 0) {
+        if bases.len() < 2 * period {
+            continue;
+        }
+        // Whether each base matches the base one period downstream.
+        let matches: Vec<bool> = (0..bases.len() - period)
+            .map(|index| bases[index] == bases[index + period] && bases[index] != b'N')
+            .collect();
+
+        let mut index: usize = 0;
+        while index < matches.len() {
+            if !matches[index] {
+                index += 1;
+                continue;
+            }
+
+            // Extend the run of matches, tolerating mismatches until the score drops too far below its best.
+            let score = |index: usize| if matches[index] { MATCH_SCORE } else { -MISMATCH_PENALTY };
+            let first_match: usize = index;
+            let mut total: i64 = 0;
+            let mut best_score: i64 = 0;
+            let mut best_end: usize = index;
+            while index < matches.len() {
+                total += score(index);
+                if total > best_score {
+                    best_score = total;
+                    best_end = index + 1;
+                }
+                if best_score - total >= DROP_OFF {
+                    break;
+                }
+                index += 1;
+            }
+            index = best_end;
+
+            // Trim the leading bases that lower the score of the run.
+            let mut start: usize = first_match;
+            let mut lowest_score: i64 = 0;
+            total = 0;
+            for position in first_match..best_end {
+                total += score(position);
+                if total < lowest_score {
+                    lowest_score = total;
+                    start = position + 1;
+                }
+            }
+
+            let end: usize = best_end + period;
+            if end - start < minimum_length.max(2 * period) {
+                continue;
+            }
+            let (motif, purity) = consensus(&bases[start..end], period);
+            if purity < minimum_purity || !is_primitive(&motif) {
+                continue;
+            }
+            repeats.push(TandemRepeat::new(
+                super::Sequence::new(start, end),
+                period,
+                &motif,
+                (end - start) as f64 / period as f64,
+                purity,
+            ));
+        }
+    }
+
+    repeats.sort_by_key(|repeat| (repeat.sequence, repeat.period));
+    repeats
+}
+
+/// Return the majority base at each phase of the period, and the fraction of bases agreeing with it.
+fn consensus(bases: &[u8], period: usize) -> (String, f64) {
+    let mut motif: String = String::with_capacity(period);
+    let mut agreeing: usize = 0;
+    for phase in 0..period {
+        let mut counts: std::collections::BTreeMap<u8, usize> = std::collections::BTreeMap::new();
+        for &base in bases.iter().skip(phase).step_by(period) {
+            *counts.entry(base).or_insert(0) += 1;
+        }
+        // Prefer the base seen first in the run when counts are tied.
+        let first: u8 = bases[phase];
+        let (base, count) = counts
+            .iter()
+            .max_by_key(|(&base, &count)| (count, base == first))
+            .map(|(&base, &count)| (base, count))
+            .unwrap_or((first, 0));
+        motif.push(base as char);
+        agreeing += count;
+    }
+    (motif, agreeing as f64 / bases.len() as f64)
+}
+
+/// Whether a motif is not itself a repetition of a shorter motif.
+fn is_primitive(motif: &str) -> bool {
+    let length: usize = motif.len();
+    !(1..length)
+        .filter(|&period| length.is_multiple_of(period))
+        .any(|period| motif.as_bytes().chunks(period).all(|chunk| chunk == &motif.as_bytes()[..period]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_tandem_repeats_on_homopolymer() {
+        let repeats: Vec<TandemRepeat> = find_tandem_repeats("CGAAAAAAAAAAAATC", MICROSATELLITE_PERIODS, 10, 0.9);
+        assert_eq!(repeats.len(), 1);
+        assert_eq!(repeats[0].get_motif(), "A");
+        assert_eq!(repeats[0].get_sequence(), crate::seq::Sequence::new(2, 14));
+    }
+
+    #[test]
+    fn test_find_tandem_repeats_on_imperfect_repeat() {
+        let repeats: Vec<TandemRepeat> = find_tandem_repeats("ccgtagatagatagttagatagatagatgcc", MICROSATELLITE_PERIODS, 12, 0.8);
+        assert_eq!(repeats.len(), 1);
+        assert_eq!(repeats[0].get_motif(), "TAGA");
+        assert_eq!(repeats[0].get_period(), 4);
+        assert!(repeats[0].get_purity() < 1.0);
+    }
+
+    #[test]
+    fn test_find_tandem_repeats_on_unknown_bases() {
+        let repeats: Vec<TandemRepeat> = find_tandem_repeats("NNNNNNNNNNNNNNNN", MICROSATELLITE_PERIODS, 10, 0.9);
+        assert!(repeats.is_empty());
+    }
+
+    #[test]
+    fn test_find_tandem_repeats_on_minisatellite() {
+        let unit: &str = "GGAATGCAGTCCA";
+        let dna: String = format!("TT{}TT", unit.repeat(4));
+        let repeats: Vec<TandemRepeat> = find_tandem_repeats(&dna, MINISATELLITE_PERIODS, 20, 0.9);
+        assert_eq!(repeats.len(), 1);
+        assert_eq!(repeats[0].get_motif(), unit);
+        assert_eq!(repeats[0].get_copy_number(), 4.0);
+    }
+}
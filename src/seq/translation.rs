@@ -0,0 +1,591 @@
+//! MIT license.
+
+use serde::ser::SerializeStruct;
+
+/// An NCBI genetic code: the amino acid encoded by each codon, and the codons that can initiate translation.
+///
+/// Codons are ordered as in NCBI's tables, with bases in T, C, A, G order: TTT, TTC, TTA, TTG, TCT, ..., GGG.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct GeneticCode {
+    id: u8,
+    name: &'static str,
+    amino_acids: &'static str,
+    starts: &'static str,
+}
+
+/// The NCBI genetic codes, ordered by their NCBI translation table identifier.
+///
+/// The stop codons of tables 27, 28 and 31 depend on their context: their codons that may end translation are read
+/// as the amino acids they encode within a gene, as NCBI's tables list them, and are stop codons only at its end.
+pub const GENETIC_CODES: [GeneticCode; 25] = [
+    GeneticCode {
+        id: 1,
+        name: "Standard",
+        amino_acids: "FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "---M---------------M---------------M----------------------------",
+    },
+    GeneticCode {
+        id: 2,
+        name: "Vertebrate Mitochondrial",
+        amino_acids: "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSS**VVVVAAAADDEEGGGG",
+        starts: "--------------------------------MMMM---------------M------------",
+    },
+    GeneticCode {
+        id: 3,
+        name: "Yeast Mitochondrial",
+        amino_acids: "FFLLSSSSYY**CCWWTTTTPPPPHHQQRRRRIIMMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "----------------------------------MM---------------M------------",
+    },
+    GeneticCode {
+        id: 4,
+        name: "Mold, Protozoan, and Coelenterate Mitochondrial and Mycoplasma/Spiroplasma",
+        amino_acids: "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "--MM---------------M------------MMMM---------------M------------",
+    },
+    GeneticCode {
+        id: 5,
+        name: "Invertebrate Mitochondrial",
+        amino_acids: "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSSSSVVVVAAAADDEEGGGG",
+        starts: "---M----------------------------MMMM---------------M------------",
+    },
+    GeneticCode {
+        id: 6,
+        name: "Ciliate, Dasycladacean and Hexamita Nuclear",
+        amino_acids: "FFLLSSSSYYQQCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "-----------------------------------M----------------------------",
+    },
+    GeneticCode {
+        id: 9,
+        name: "Echinoderm and Flatworm Mitochondrial",
+        amino_acids: "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNNKSSSSVVVVAAAADDEEGGGG",
+        starts: "-----------------------------------M---------------M------------",
+    },
+    GeneticCode {
+        id: 10,
+        name: "Euplotid Nuclear",
+        amino_acids: "FFLLSSSSYY**CCCWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "-----------------------------------M----------------------------",
+    },
+    GeneticCode {
+        id: 11,
+        name: "Bacterial, Archaeal and Plant Plastid",
+        amino_acids: "FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "---M---------------M------------MMMM---------------M------------",
+    },
+    GeneticCode {
+        id: 12,
+        name: "Alternative Yeast Nuclear",
+        amino_acids: "FFLLSSSSYY**CC*WLLLSPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "-------------------M---------------M----------------------------",
+    },
+    GeneticCode {
+        id: 13,
+        name: "Ascidian Mitochondrial",
+        amino_acids: "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSSGGVVVVAAAADDEEGGGG",
+        starts: "---M------------------------------MM---------------M------------",
+    },
+    GeneticCode {
+        id: 14,
+        name: "Alternative Flatworm Mitochondrial",
+        amino_acids: "FFLLSSSSYYY*CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNNKSSSSVVVVAAAADDEEGGGG",
+        starts: "-----------------------------------M----------------------------",
+    },
+    GeneticCode {
+        id: 16,
+        name: "Chlorophycean Mitochondrial",
+        amino_acids: "FFLLSSSSYY*LCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "-----------------------------------M----------------------------",
+    },
+    GeneticCode {
+        id: 21,
+        name: "Trematode Mitochondrial",
+        amino_acids: "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNNKSSSSVVVVAAAADDEEGGGG",
+        starts: "-----------------------------------M---------------M------------",
+    },
+    GeneticCode {
+        id: 22,
+        name: "Scenedesmus obliquus Mitochondrial",
+        amino_acids: "FFLLSS*SYY*LCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "-----------------------------------M----------------------------",
+    },
+    GeneticCode {
+        id: 23,
+        name: "Thraustochytrium Mitochondrial",
+        amino_acids: "FF*LSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "--------------------------------M--M---------------M------------",
+    },
+    GeneticCode {
+        id: 24,
+        name: "Rhabdopleuridae Mitochondrial",
+        amino_acids: "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSSKVVVVAAAADDEEGGGG",
+        starts: "---M---------------M---------------M---------------M------------",
+    },
+    GeneticCode {
+        id: 25,
+        name: "Candidate Division SR1 and Gracilibacteria",
+        amino_acids: "FFLLSSSSYY**CCGWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "---M-------------------------------M---------------M------------",
+    },
+    GeneticCode {
+        id: 26,
+        name: "Pachysolen tannophilus Nuclear",
+        amino_acids: "FFLLSSSSYY**CC*WLLLAPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "-------------------M---------------M----------------------------",
+    },
+    // TGA encodes tryptophan, or ends translation at a gene's end.
+    GeneticCode {
+        id: 27,
+        name: "Karyorelict Nuclear",
+        amino_acids: "FFLLSSSSYYQQCCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "-----------------------------------M----------------------------",
+    },
+    // TAA and TAG encode glutamine, and TGA tryptophan, or each ends translation at a gene's end.
+    GeneticCode {
+        id: 28,
+        name: "Condylostoma Nuclear",
+        amino_acids: "FFLLSSSSYYQQCCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "-----------------------------------M----------------------------",
+    },
+    GeneticCode {
+        id: 29,
+        name: "Mesodinium Nuclear",
+        amino_acids: "FFLLSSSSYYYYCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "-----------------------------------M----------------------------",
+    },
+    GeneticCode {
+        id: 30,
+        name: "Peritrich Nuclear",
+        amino_acids: "FFLLSSSSYYEECC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "-----------------------------------M----------------------------",
+    },
+    // TAA and TAG encode glutamic acid, or end translation at a gene's end.
+    GeneticCode {
+        id: 31,
+        name: "Blastocrithidia Nuclear",
+        amino_acids: "FFLLSSSSYYEECCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "-----------------------------------M----------------------------",
+    },
+    GeneticCode {
+        id: 33,
+        name: "Cephalodiscidae Mitochondrial",
+        amino_acids: "FFLLSSSSYYY*CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSSKVVVVAAAADDEEGGGG",
+        starts: "---M---------------M---------------M---------------M------------",
+    },
+];
+
+/// The position of each base in NCBI's T, C, A, G codon ordering, indexed by the base's 2-bit code.
+const TCAG_ORDER: [usize; 4] = [2, 1, 3, 0];
+
+impl GeneticCode {
+    /// Return the genetic code with an NCBI translation table identifier.
+    pub fn from_id(id: u8) -> Option<&'static GeneticCode> {
+        GENETIC_CODES.iter().find(|code| code.id == id)
+    }
+
+    /// The standard genetic code, NCBI translation table 1.
+    pub fn standard() -> &'static GeneticCode {
+        &GENETIC_CODES[0]
+    }
+
+    /// The NCBI translation table identifier.
+    pub fn get_id(&self) -> u8 {
+        self.id
+    }
+
+    pub fn get_name(&self) -> &str {
+        self.name
+    }
+
+    /// Return the one-letter code of the amino acid a codon encodes, `*` for a stop codon.
+    ///
+    /// An ambiguous codon translates to an amino acid only when every codon it may stand for encodes that amino acid,
+    /// otherwise it translates to `X`.
+    pub fn translate_codon(&self, codon: [super::nucleotide::Nucleotide; 3]) -> char {
+        let mut amino_acid: Option<char> = None;
+        for &first in codon[0].get_bases() {
+            for &second in codon[1].get_bases() {
+                for &third in codon[2].get_bases() {
+                    let residue: char = self.amino_acids.as_bytes()[Self::index([first, second, third])] as char;
+                    if amino_acid.is_some_and(|amino_acid| amino_acid != residue) {
+                        return 'X';
+                    }
+                    amino_acid = Some(residue);
+                }
+            }
+        }
+        amino_acid.unwrap_or('X')
+    }
+
+    /// Return whether an unambiguous codon can initiate translation.
+    pub fn is_start(&self, codon: [super::nucleotide::Nucleotide; 3]) -> bool {
+        codon.iter().all(|nucleotide| !nucleotide.is_ambiguous()) && self.starts.as_bytes()[Self::index(codon)] == b'M'
+    }
+
+    /// Return whether a codon, or every codon an ambiguous codon may stand for, ends translation.
+    pub fn is_stop(&self, codon: [super::nucleotide::Nucleotide; 3]) -> bool {
+        self.translate_codon(codon) == '*'
+    }
+
+    /// Return the codons that can initiate translation, in NCBI's T, C, A, G order.
+    pub fn get_start_codons(&self) -> Vec<String> {
+        let bases: [char; 4] = ['T', 'C', 'A', 'G'];
+        self.starts
+            .bytes()
+            .enumerate()
+            .filter(|&(_, start)| start == b'M')
+            .map(|(index, _)| [bases[index / 16], bases[index / 4 % 4], bases[index % 4]].iter().collect())
+            .collect()
+    }
+
+    /// The index of an unambiguous codon in NCBI's T, C, A, G ordering.
+    fn index(codon: [super::nucleotide::Nucleotide; 3]) -> usize {
+        codon.iter().fold(0, |index, nucleotide| {
+            4 * index + nucleotide.to_bits().map_or(0, |bits| TCAG_ORDER[usize::from(bits)])
+        })
+    }
+}
+
+/// A character that is not an IUPAC amino acid code.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AminoAcidError {
+    character: char,
+    position: usize,
+}
+
+impl AminoAcidError {
+    pub fn get_character(&self) -> char {
+        self.character
+    }
+
+    /// The index of the character in the sequence being parsed.
+    pub fn get_position(&self) -> usize {
+        self.position
+    }
+}
+
+impl std::fmt::Display for AminoAcidError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid amino acid '{}' at position {}",
+            self.character.escape_default(),
+            self.position
+        )
+    }
+}
+
+impl std::error::Error for AminoAcidError {}
+
+/// The IUPAC one-letter amino acid codes, including the ambiguity codes B, Z, J and X, and `*` for a stop codon.
+const AMINO_ACIDS: &str = "ACDEFGHIKLMNPQRSTVWYBZJUOX*";
+
+/// A sequence of amino acids as upper-case IUPAC one-letter codes.
+///
+/// # Example
+///
+/// ```
+/// let protein: utils::seq::translation::ProteinSequence = "mkv*".parse().unwrap();
+/// assert_eq!(protein.to_string(), "MKV*");
+/// assert_eq!(protein.len(), 4);
+///
+/// let error = "MK1".parse::<utils::seq::translation::ProteinSequence>().unwrap_err();
+/// assert_eq!(error.to_string(), "invalid amino acid '1' at position 2");
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ProteinSequence {
+    residues: String,
+}
+
+impl ProteinSequence {
+    pub fn new() -> ProteinSequence {
+        ProteinSequence::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.residues.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.residues.is_empty()
+    }
+
+    pub fn get_residues(&self) -> &str {
+        &self.residues
+    }
+}
+
+impl std::str::FromStr for ProteinSequence {
+    type Err = AminoAcidError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let residues: String = string.to_ascii_uppercase();
+        match residues.chars().position(|residue| !AMINO_ACIDS.contains(residue)) {
+            Some(position) => Err(AminoAcidError {
+                character: string.chars().nth(position).unwrap_or_default(),
+                position,
+            }),
+            None => Ok(ProteinSequence { residues }),
+        }
+    }
+}
+
+impl std::fmt::Display for ProteinSequence {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.residues)
+    }
+}
+
+impl serde::ser::Serialize for ProteinSequence {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+/// The translation of one of the three reading frames of one strand of a DNA sequence.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FrameTranslation {
+    strand: super::Strand,
+    frame: usize,
+    protein: ProteinSequence,
+}
+
+impl FrameTranslation {
+    pub fn get_strand(&self) -> super::Strand {
+        self.strand
+    }
+
+    /// The offset, 0, 1 or 2, of the first translated codon from the start of the strand.
+    pub fn get_frame(&self) -> usize {
+        self.frame
+    }
+
+    pub fn get_protein(&self) -> &ProteinSequence {
+        &self.protein
+    }
+}
+
+/// An open reading frame: a start codon followed in frame by a stop codon.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OpenReadingFrame {
+    sequence: super::Sequence,
+    frame: usize,
+    protein: ProteinSequence,
+}
+
+impl OpenReadingFrame {
+    /// The position of the open reading frame, from the first base of its start codon to the last base of its stop codon.
+    pub fn get_sequence(&self) -> super::Sequence {
+        self.sequence
+    }
+
+    /// The offset, 0, 1 or 2, of the open reading frame's codons from the start of its strand.
+    pub fn get_frame(&self) -> usize {
+        self.frame
+    }
+
+    /// The translated amino acids, excluding the stop codon.
+    pub fn get_protein(&self) -> &ProteinSequence {
+        &self.protein
+    }
+
+    /// Return the open reading frame at another position, such as on the chromosome.
+    pub fn with_sequence(&self, sequence: super::Sequence) -> OpenReadingFrame {
+        OpenReadingFrame {
+            sequence,
+            ..self.clone()
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String, Box<dyn std::error::Error>> {
+        Ok(serde_json::to_string(&self)?)
+    }
+}
+
+impl serde::ser::Serialize for OpenReadingFrame {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        let mut serialized_frame = serializer.serialize_struct("OpenReadingFrame", 3)?;
+        serialized_frame.serialize_field("sequence", &self.sequence)?;
+        serialized_frame.serialize_field("frame", &self.frame)?;
+        serialized_frame.serialize_field("protein", &self.protein)?;
+        serialized_frame.end()
+    }
+}
+
+/// Translate the codons of a DNA sequence starting `frame` bases in, ignoring any incomplete final codon.
+///
+/// # Example
+///
+/// ```
+/// let dna: utils::seq::nucleotide::DnaSequence = "ATGGCNTGAtgg".parse().unwrap();
+/// let standard = utils::seq::translation::GeneticCode::standard();
+/// assert_eq!(utils::seq::translation::translate(&dna, standard, 0).to_string(), "MA*W");
+///
+/// let mitochondrial = utils::seq::translation::GeneticCode::from_id(2).unwrap();
+/// assert_eq!(utils::seq::translation::translate(&dna, mitochondrial, 0).to_string(), "MAWW");
+/// ```
+pub fn translate(dna: &super::nucleotide::DnaSequence, code: &GeneticCode, frame: usize) -> ProteinSequence {
+    let residues: String = (frame..dna.len().saturating_sub(2))
+        .step_by(3)
+        .map(|position| code.translate_codon(codon(dna, position)))
+        .collect();
+    ProteinSequence { residues }
+}
+
+/// Translate the three reading frames of each strand of a DNA sequence, forward strand frames first.
+pub fn six_frame_translation(dna: &super::nucleotide::DnaSequence, code: &GeneticCode) -> Vec<FrameTranslation> {
+    let reverse_complement: super::nucleotide::DnaSequence = dna.reverse_complement();
+    [(super::Strand::Forward, dna), (super::Strand::Reverse, &reverse_complement)]
+        .into_iter()
+        .flat_map(|(strand, dna)| {
+            (0..3).map(move |frame| FrameTranslation {
+                strand,
+                frame,
+                protein: translate(dna, code, frame),
+            })
+        })
+        .collect()
+}
+
+/// Find the open reading frames on both strands of a DNA sequence that encode at least `minimum_length` amino acids,
+/// ordered by position.
+///
+/// Each open reading frame begins at the first of `start_codons` following the previous stop codon in its frame,
+/// and its start codon is translated as methionine. Positions are 0-based and end-exclusive on the forward strand,
+/// like [`super::Sequences::find`].
+///
+/// # Example
+///
+/// ```
+/// let dna: utils::seq::nucleotide::DnaSequence = "CCATGAAACCCTAGTT".parse().unwrap();
+/// let code = utils::seq::translation::GeneticCode::standard();
+/// let frames = utils::seq::translation::find_open_reading_frames(&dna, code, 3, &["ATG"]);
+/// assert_eq!(frames.len(), 1);
+/// assert_eq!(frames[0].get_sequence(), utils::seq::Sequence::new(2, 14));
+/// assert_eq!(frames[0].get_protein().to_string(), "MKP");
+///
+/// // The genetic code's start codons include alternative start codons, such as CTG and TTG in the standard code.
+/// let frames = utils::seq::translation::find_open_reading_frames(&dna, code, 3, &code.get_start_codons());
+/// assert_eq!(frames.len(), 1);
+/// ```
+pub fn find_open_reading_frames<S: AsRef<str>>(
+    dna: &super::nucleotide::DnaSequence,
+    code: &GeneticCode,
+    minimum_length: usize,
+    start_codons: &[S],
+) -> Vec<OpenReadingFrame> {
+    let start_codons: Vec<String> = start_codons
+        .iter()
+        .map(|start_codon| start_codon.as_ref().to_ascii_uppercase())
+        .collect();
+    let length: usize = dna.len();
+    let reverse_complement: super::nucleotide::DnaSequence = dna.reverse_complement();
+
+    let mut frames: Vec<OpenReadingFrame> = Vec::new();
+    for (strand, dna) in [(super::Strand::Forward, dna), (super::Strand::Reverse, &reverse_complement)] {
+        for frame in 0..3 {
+            let mut start: Option<usize> = None;
+            for position in (frame..length.saturating_sub(2)).step_by(3) {
+                let codon: [super::nucleotide::Nucleotide; 3] = codon(dna, position);
+                if start.is_none() && is_start_codon(codon, &start_codons) {
+                    start = Some(position);
+                }
+                if !code.is_stop(codon) {
+                    continue;
+                }
+                let Some(start) = start.take() else {
+                    continue;
+                };
+                if (position - start) / 3 < minimum_length {
+                    continue;
+                }
+
+                let mut protein: ProteinSequence = translate(&dna.slice(start, position), code, 0);
+                if !protein.is_empty() {
+                    protein.residues.replace_range(0..1, "M");
+                }
+                let end: usize = position + 3;
+                let sequence: super::Sequence = match strand {
+                    super::Strand::Forward => super::Sequence::with_strand(start, end, strand),
+                    super::Strand::Reverse => super::Sequence::with_strand(length - end, length - start, strand),
+                };
+                frames.push(OpenReadingFrame { sequence, frame, protein });
+            }
+        }
+    }
+    frames.sort_by_key(|frame| frame.sequence);
+    frames
+}
+
+fn codon(dna: &super::nucleotide::DnaSequence, position: usize) -> [super::nucleotide::Nucleotide; 3] {
+    [0, 1, 2].map(|offset| dna.get(position + offset).unwrap_or(super::nucleotide::Nucleotide::N))
+}
+
+fn is_start_codon(codon: [super::nucleotide::Nucleotide; 3], start_codons: &[String]) -> bool {
+    let codon: String = codon.iter().map(|nucleotide| nucleotide.to_char()).collect();
+    start_codons.contains(&codon)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_genetic_codes_on_table_lengths() {
+        for code in GENETIC_CODES.iter() {
+            assert_eq!(code.amino_acids.len(), 64, "table {}", code.get_id());
+            assert_eq!(code.starts.len(), 64, "table {}", code.get_id());
+            assert!(code.get_start_codons().contains(&String::from("ATG")), "table {}", code.get_id());
+        }
+    }
+
+    #[test]
+    fn test_translate_codon_on_ambiguous_codons() {
+        let dna: crate::seq::nucleotide::DnaSequence = "GCNTTRTAYNNN".parse().unwrap();
+        assert_eq!(translate(&dna, GeneticCode::standard(), 0).to_string(), "ALYX");
+        // TAR is a stop codon in the standard code, but TAA and TAG encode glutamine in the ciliate code.
+        let dna: crate::seq::nucleotide::DnaSequence = "TARTRG".parse().unwrap();
+        assert_eq!(translate(&dna, GeneticCode::standard(), 0).to_string(), "*X");
+        assert_eq!(translate(&dna, GeneticCode::from_id(6).unwrap(), 0).to_string(), "QX");
+    }
+
+    #[test]
+    fn test_genetic_codes_on_context_dependent_stop_codons() {
+        let dna: crate::seq::nucleotide::DnaSequence = "TAATAGTGA".parse().unwrap();
+        let translations: Vec<String> = [27, 28, 29, 30, 31, 33]
+            .iter()
+            .map(|&id| translate(&dna, GeneticCode::from_id(id).unwrap(), 0).to_string())
+            .collect();
+        assert_eq!(translations, ["QQW", "QQW", "YY*", "EE*", "EEW", "Y*W"]);
+        assert!(GeneticCode::from_id(32).is_none());
+    }
+
+    #[test]
+    fn test_six_frame_translation() {
+        let dna: crate::seq::nucleotide::DnaSequence = "ATGCCCTAA".parse().unwrap();
+        let translations: Vec<String> = six_frame_translation(&dna, GeneticCode::standard())
+            .iter()
+            .map(|translation| translation.get_protein().to_string())
+            .collect();
+        assert_eq!(translations, vec!["MP*", "CP", "AL", "LGH", "*G", "RA"]);
+    }
+
+    #[test]
+    fn test_find_open_reading_frames_on_reverse_strand() {
+        // The reverse complement of ATGAAATGA followed by two bases.
+        let dna: crate::seq::nucleotide::DnaSequence = "TCATTTCATGG".parse().unwrap();
+        let frames: Vec<OpenReadingFrame> = find_open_reading_frames(&dna, GeneticCode::standard(), 2, &["ATG"]);
+        assert_eq!(frames.len(), 1);
+        assert_eq!(
+            frames[0].get_sequence(),
+            crate::seq::Sequence::with_strand(0, 9, crate::seq::Strand::Reverse)
+        );
+        assert_eq!(frames[0].get_frame(), 2);
+        assert_eq!(frames[0].get_protein().to_string(), "MK");
+    }
+}
@@ -1,6 +1,8 @@
 //! MIT license.
 
 /// If the system time is less than the timestamp provided then sleep for a duration of time provided in milliseconds.
+///
+/// The thread is blocked while sleeping; requests made with `web` are paced by `rate_limit::global()` instead.
 /// 
 /// # Example
 ///
@@ -25,4 +27,33 @@ pub fn sleep_until_time_elapsed(timestamp: std::time::SystemTime, milliseconds:
             }
         }
     }
-}
\ No newline at end of file
+}
+
+/// The calendar date of a time in Coordinated Universal Time (UTC), formatted as YYYY-MM-DD.
+///
+/// # Example
+///
+/// ```
+/// let moment = std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_709_251_199);
+/// assert_eq!(utils::time::utc_date(moment), "2024-02-29");
+/// assert_eq!(utils::time::utc_date(std::time::UNIX_EPOCH), "1970-01-01");
+/// ```
+pub fn utc_date(time: std::time::SystemTime) -> String {
+    let days: i64 = match time.duration_since(std::time::UNIX_EPOCH) {
+        Ok(duration) => (duration.as_secs() / 86_400) as i64,
+        Err(error) => -(error.duration().as_secs().div_ceil(86_400) as i64),
+    };
+
+    // Convert days since 1970-01-01 to a date of the proleptic Gregorian calendar, counting years from March
+    // so that leap days fall at the end of a year, in eras of 400 years.
+    let days: i64 = days + 719_468;
+    let era: i64 = days.div_euclid(146_097);
+    let day_of_era: i64 = days.rem_euclid(146_097);
+    let year_of_era: i64 = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year: i64 = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march: i64 = (5 * day_of_year + 2) / 153;
+    let day: i64 = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+    let month: i64 = if month_from_march < 10 { month_from_march + 3 } else { month_from_march - 9 };
+    let year: i64 = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
@@ -0,0 +1,330 @@
+//! MIT license.
+
+/// An exon of a transcript, as returned by EnsEMBL's expanded `/lookup/id`.
+///
+/// Like a gene's, an exon's coordinates are 1-based and inclusive on its chromosome.
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize)]
+#[serde(default)]
+pub struct Exon {
+    id: String,
+    start: u64,
+    end: u64,
+    strand: Option<crate::seq::Strand>,
+    version: Option<u64>,
+}
+
+impl Exon {
+    pub fn new(id: &str, start: u64, end: u64, strand: crate::seq::Strand) -> Exon {
+        Exon {
+            id: String::from(id),
+            start,
+            end,
+            strand: Some(strand),
+            version: None,
+        }
+    }
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn get_start(&self) -> u64 {
+        self.start
+    }
+
+    pub fn get_end(&self) -> u64 {
+        self.end
+    }
+
+    pub fn get_strand(&self) -> Option<crate::seq::Strand> {
+        self.strand
+    }
+
+    pub fn get_version(&self) -> Option<u64> {
+        self.version
+    }
+}
+
+/// The translation of a protein coding transcript: the chromosome positions of the first and last bases
+/// of its coding sequence, start codon to stop codon.
+///
+/// The start is always less than the end, whichever strand the transcript is on.
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize)]
+#[serde(default)]
+pub struct Translation {
+    id: String,
+    start: u64,
+    end: u64,
+    length: Option<u64>,
+    version: Option<u64>,
+}
+
+impl Translation {
+    pub fn new(id: &str, start: u64, end: u64) -> Translation {
+        Translation {
+            id: String::from(id),
+            start,
+            end,
+            length: None,
+            version: None,
+        }
+    }
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn get_start(&self) -> u64 {
+        self.start
+    }
+
+    pub fn get_end(&self) -> u64 {
+        self.end
+    }
+
+    /// The number of amino acids EnsEMBL reports the translation to have.
+    pub fn get_length(&self) -> Option<u64> {
+        self.length
+    }
+
+    pub fn get_version(&self) -> Option<u64> {
+        self.version
+    }
+}
+
+/// An untranslated region of a transcript, as returned by EnsEMBL's expanded `/lookup/id` with `utr=1`.
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize)]
+#[serde(default)]
+pub struct Utr {
+    #[serde(rename = "type")]
+    utr_type: String,
+    start: u64,
+    end: u64,
+    strand: Option<crate::seq::Strand>,
+}
+
+impl Utr {
+    /// The kind of untranslated region: `five_prime_UTR` or `three_prime_UTR`.
+    pub fn get_type(&self) -> &str {
+        &self.utr_type
+    }
+
+    pub fn get_start(&self) -> u64 {
+        self.start
+    }
+
+    pub fn get_end(&self) -> u64 {
+        self.end
+    }
+
+    pub fn get_strand(&self) -> Option<crate::seq::Strand> {
+        self.strand
+    }
+
+    pub fn is_five_prime(&self) -> bool {
+        self.utr_type == "five_prime_UTR"
+    }
+
+    pub fn is_three_prime(&self) -> bool {
+        self.utr_type == "three_prime_UTR"
+    }
+}
+
+/// A transcript of a gene, with its exons, translation and untranslated regions, as returned by
+/// EnsEMBL's expanded `/lookup/id`.
+///
+/// # Example
+///
+/// ```
+/// let json: &str = r#"{
+///     "id": "ENST00000000001", "version": 1, "biotype": "protein_coding", "is_canonical": 1,
+///     "start": 101, "end": 120, "strand": -1,
+///     "Exon": [{"id": "ENSE00000000002", "start": 112, "end": 120}, {"id": "ENSE00000000001", "start": 101, "end": 108}],
+///     "Translation": {"id": "ENSP00000000001", "start": 103, "end": 117, "length": 3},
+///     "UTR": [{"type": "five_prime_UTR", "start": 118, "end": 120}, {"type": "three_prime_UTR", "start": 101, "end": 102}]
+/// }"#;
+/// let transcript: utils::transcript::Transcript = serde_json::from_str(json).unwrap();
+/// assert!(transcript.is_canonical());
+/// assert_eq!(transcript.get_versioned_id(), "ENST00000000001.1");
+/// assert!(transcript.get_utrs()[0].is_five_prime());
+///
+/// // The forward strand DNA from the first to the last base of the transcript.
+/// let dna: &str = "aaCTATTTaagAGCCATgcc";
+/// assert_eq!(transcript.get_cdna(dna, 101).unwrap(), "ggcATGGCTAAATAGtt");
+/// assert_eq!(transcript.get_cds(dna, 101).unwrap(), "ATGGCTAAATAG");
+/// let code: &utils::seq::translation::GeneticCode = utils::seq::translation::GeneticCode::standard();
+/// assert_eq!(transcript.get_protein(dna, 101, code).unwrap().to_string(), "MAK");
+/// assert!(transcript.get_cdna(dna, 102).is_err());
+///
+/// // An exon ending before it starts, such as one without an end, is an error.
+/// let json: &str = r#"{"id": "ENST00000000002", "start": 101, "end": 120, "Exon": [{"start": 101}]}"#;
+/// let transcript: utils::transcript::Transcript = serde_json::from_str(json).unwrap();
+/// assert!(transcript.get_cdna(dna, 101).is_err());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize)]
+#[serde(default)]
+pub struct Transcript {
+    id: String,
+    display_name: Option<String>,
+    biotype: String,
+    start: u64,
+    end: u64,
+    strand: Option<crate::seq::Strand>,
+    version: Option<u64>,
+    #[serde(deserialize_with = "deserialize_flag")]
+    is_canonical: bool,
+    #[serde(rename = "Exon")]
+    exons: Vec<Exon>,
+    #[serde(rename = "Translation")]
+    translation: Option<Translation>,
+    #[serde(rename = "UTR")]
+    utrs: Vec<Utr>,
+}
+
+impl Transcript {
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn get_display_name(&self) -> Option<&str> {
+        self.display_name.as_deref()
+    }
+
+    pub fn get_biotype(&self) -> &str {
+        &self.biotype
+    }
+
+    pub fn get_start(&self) -> u64 {
+        self.start
+    }
+
+    pub fn get_end(&self) -> u64 {
+        self.end
+    }
+
+    pub fn get_strand(&self) -> Option<crate::seq::Strand> {
+        self.strand
+    }
+
+    pub fn get_version(&self) -> Option<u64> {
+        self.version
+    }
+
+    /// The identifier followed by the version, such as `ENST00000285947.5`, as a gene's canonical transcript is named.
+    pub fn get_versioned_id(&self) -> String {
+        match self.version {
+            Some(version) => format!("{}.{}", self.id, version),
+            None => self.id.clone(),
+        }
+    }
+
+    pub fn is_canonical(&self) -> bool {
+        self.is_canonical
+    }
+
+    pub fn get_exons(&self) -> &[Exon] {
+        &self.exons
+    }
+
+    /// The translation of a protein coding transcript.
+    pub fn get_translation(&self) -> Option<&Translation> {
+        self.translation.as_ref()
+    }
+
+    pub fn get_utrs(&self) -> &[Utr] {
+        &self.utrs
+    }
+
+    /// Assemble the spliced cDNA, in the direction of transcription, from the forward strand DNA of a region
+    /// starting at the 1-based chromosome position `dna_start`, such as a gene's DNA and start.
+    pub fn get_cdna(&self, dna: &str, dna_start: u64) -> Result<String, Box<dyn std::error::Error>> {
+        let exons: Vec<(u64, u64)> = self.exons.iter().map(|exon| (exon.start, exon.end)).collect();
+        self.splice(dna, dna_start, &exons)
+    }
+
+    /// Assemble the coding sequence, start codon to stop codon in the direction of transcription, from the forward strand DNA
+    /// of a region starting at the 1-based chromosome position `dna_start`, such as a gene's DNA and start.
+    pub fn get_cds(&self, dna: &str, dna_start: u64) -> Result<String, Box<dyn std::error::Error>> {
+        let Some(translation) = &self.translation else {
+            return Err(Box::new(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("transcript {} has no translation", self.id),
+            )));
+        };
+        let coding_exons: Vec<(u64, u64)> = self
+            .exons
+            .iter()
+            .filter(|exon| exon.end >= translation.start && exon.start <= translation.end)
+            .map(|exon| (exon.start.max(translation.start), exon.end.min(translation.end)))
+            .collect();
+        self.splice(dna, dna_start, &coding_exons)
+    }
+
+    /// Translate the coding sequence with a genetic code, excluding the stop codon.
+    pub fn get_protein(
+        &self,
+        dna: &str,
+        dna_start: u64,
+        code: &crate::seq::translation::GeneticCode,
+    ) -> Result<crate::seq::translation::ProteinSequence, Box<dyn std::error::Error>> {
+        let cds: crate::seq::nucleotide::DnaSequence = self.get_cds(dna, dna_start)?.parse()?;
+        let protein: String = crate::seq::translation::translate(&cds, code, 0).to_string();
+        Ok(protein.trim_end_matches('*').parse()?)
+    }
+
+    /// Join the 1-based, inclusive chromosome intervals of `dna`, ordered along the transcript's strand.
+    ///
+    /// An interval outside of `dna`, or ending before it starts, is an error.
+    fn splice(&self, dna: &str, dna_start: u64, intervals: &[(u64, u64)]) -> Result<String, Box<dyn std::error::Error>> {
+        let mut intervals: Vec<(u64, u64)> = intervals.to_vec();
+        intervals.sort();
+
+        let mut spliced: String = String::new();
+        for (start, end) in intervals {
+            let range: Option<std::ops::Range<usize>> = match (start.checked_sub(dna_start), end.checked_sub(dna_start)) {
+                (Some(first), Some(last)) if first <= last => Some(first as usize..last as usize + 1),
+                _ => None,
+            };
+            match range.and_then(|range| dna.get(range)) {
+                Some(sequence) => spliced.push_str(sequence),
+                None => {
+                    return Err(Box::new(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        format!(
+                            "{}:{} of transcript {} is not an interval of the DNA from {} to {}",
+                            start,
+                            end,
+                            self.id,
+                            dna_start,
+                            (dna_start + dna.len() as u64).saturating_sub(1)
+                        ),
+                    )))
+                }
+            }
+        }
+
+        match self.strand {
+            Some(crate::seq::Strand::Reverse) => Ok(crate::seq::reverse_complement(&spliced)),
+            _ => Ok(spliced),
+        }
+    }
+}
+
+/// Deserialize a flag EnsEMBL returns as 0 or 1, or as a boolean.
+fn deserialize_flag<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    #[derive(serde::Deserialize)]
+    #[serde(untagged)]
+    enum Flag {
+        Boolean(bool),
+        Number(u64),
+    }
+
+    match <Option<Flag> as serde::de::Deserialize>::deserialize(deserializer)? {
+        Some(Flag::Boolean(flag)) => Ok(flag),
+        Some(Flag::Number(flag)) => Ok(flag != 0),
+        None => Ok(false),
+    }
+}
@@ -1,6 +1,10 @@
 //! MIT license.
 
 /// Perform an HTTP request to retrieve a web page's HTML content.
+///
+/// Requests of this module wait for `rate_limit::global()` to allow them, and adapt its pace to the rate limit headers of each response.
+/// Responses are served from, and stored in, `cache::global()` if a cache is configured, and every attempt of a request
+/// to a service with a daily limit is counted in `quota::global()`. Transient failures are retried with `DEFAULT_RETRY_POLICY`; statuses that are not a success are returned as a `FetchError` (see `fetch`).
 /// 
 /// # Example
 /// 
@@ -60,13 +64,360 @@
 ///  }), expected);
 /// ```
 pub async fn get_html_body(client: &awc::Client, url: &str) -> Result<String, Box<dyn std::error::Error>> {
-    let mut client_response = client.get(url).send().await?;
-    let response_body = client_response.body().await?;
+    let key: crate::cache::CacheKey = crate::cache::CacheKey::new("GET", url);
+    let response_body = request(key, url, |timeout| client.get(url).timeout(timeout).send()).await?;
     let body = std::str::from_utf8(&response_body)?;
     Ok(String::from(body))
 }
 
-/// Build and return an Actix Web Client with DER encoded X.509 certificates.
+/// Perform an HTTP request for JSON content and deserialize the response body.
+///
+/// The request asks for `application/json` with both the `Accept` and `Content-Type` headers, as some REST APIs,
+/// like EnsEMBL's, choose the response format from the `Content-Type` header.
+pub async fn get_json<T: serde::de::DeserializeOwned>(client: &awc::Client, url: &str) -> Result<T, Box<dyn std::error::Error>> {
+    let response_body = request(json_cache_key(url), url, |timeout| {
+        JSON_REQUEST_HEADERS
+            .iter()
+            .fold(client.get(url).timeout(timeout), |request, header| request.insert_header(*header))
+            .send()
+    })
+    .await?;
+    Ok(serde_json::from_slice(&response_body)?)
+}
+
+/// The headers of a `get_json` request.
+const JSON_REQUEST_HEADERS: [(&str, &str); 2] = [("Accept", "application/json"), ("Content-Type", "application/json")];
+
+/// The cache key of a `get_json` request, so that responses cached on behalf of `get_json`, such as the lookups of a
+/// batch, are found by it.
+///
+/// # Example
+///
+/// ```
+/// let key: utils::cache::CacheKey = utils::web::json_cache_key("https://rest.ensembl.org/lookup/id/ENSG00000155542");
+/// assert_ne!(key.digest(), utils::cache::CacheKey::new("GET", key.get_url()).digest());
+/// ```
+pub fn json_cache_key(url: &str) -> crate::cache::CacheKey {
+    JSON_REQUEST_HEADERS
+        .iter()
+        .fold(crate::cache::CacheKey::new("GET", url), |key, (name, value)| key.with_header(name, value))
+}
+
+/// Perform an HTTP POST request with a JSON body and deserialize the JSON response body.
+pub async fn post_json<B: serde::ser::Serialize, T: serde::de::DeserializeOwned>(
+    client: &awc::Client,
+    url: &str,
+    body: &B,
+) -> Result<T, Box<dyn std::error::Error>> {
+    let key: crate::cache::CacheKey = crate::cache::CacheKey::new("POST", url)
+        .with_header("Accept", "application/json")
+        .with_body(&serde_json::to_vec(body)?);
+    let response_body = request(key, url, |timeout| {
+        client
+            .post(url)
+            .timeout(timeout)
+            .insert_header((awc::http::header::ACCEPT, "application/json"))
+            .send_json(body)
+    })
+    .await?;
+    Ok(serde_json::from_slice(&response_body)?)
+}
+
+/// Serve a request from `cache::global()` if it is cached, and otherwise `fetch` it with `DEFAULT_RETRY_POLICY` and cache the response.
+async fn request<F>(key: crate::cache::CacheKey, url: &str, send: F) -> Result<actix_web::web::Bytes, Box<dyn std::error::Error>>
+where
+    F: Fn(std::time::Duration) -> awc::SendClientRequest,
+{
+    let cache: Option<&crate::cache::HttpCache> = crate::cache::global();
+    if let Some(cache) = cache {
+        if let Some(body) = cache.get(&key) {
+            return Ok(actix_web::web::Bytes::from(body));
+        }
+        if cache.is_offline() {
+            return Err(Box::new(crate::cache::NotCachedError::new(url)));
+        }
+    }
+
+    let response_body = fetch(url, &DEFAULT_RETRY_POLICY, send).await?;
+    if let Some(cache) = cache {
+        if let Err(error) = cache.put(&key, &response_body) {
+            eprintln!("{{\"directory\": \"{}\", \"error\": \"{}\"}}", cache.get_directory().display(), error);
+        }
+    }
+    Ok(response_body)
+}
+
+/// The largest response body read, 64 MiB, large enough for batches of EnsEMBL sequences.
+const RESPONSE_BODY_LIMIT: usize = 64 * 1024 * 1024;
+
+/// How a request is retried: the number of attempts, the exponential backoff between them, and the time each attempt may take.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RetryPolicy {
+    /// The number of attempts made, including the first.
+    pub max_attempts: u32,
+    /// The backoff after the first failed attempt, doubled after each further failed attempt.
+    pub initial_backoff: std::time::Duration,
+    pub max_backoff: std::time::Duration,
+    /// The time an attempt may take, from sending the request to reading the whole response body.
+    pub timeout: std::time::Duration,
+}
+
+/// The retry policy of `get_html_body`, `get_json` and `post_json`: four attempts, backing off from half a second, each within a minute.
+pub const DEFAULT_RETRY_POLICY: RetryPolicy = RetryPolicy {
+    max_attempts: 4,
+    initial_backoff: std::time::Duration::from_millis(500),
+    max_backoff: std::time::Duration::from_secs(30),
+    timeout: std::time::Duration::from_secs(60),
+};
+
+impl RetryPolicy {
+    /// The time to wait after a number of failed attempts: the exponential backoff, with jitter of up to half of it
+    /// so that concurrent requests do not retry at once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let policy: utils::web::RetryPolicy = utils::web::DEFAULT_RETRY_POLICY;
+    /// let backoff: std::time::Duration = policy.backoff(3);
+    /// assert!(backoff >= std::time::Duration::from_millis(1_000));
+    /// assert!(backoff <= std::time::Duration::from_millis(2_000));
+    /// assert!(policy.backoff(20) <= policy.max_backoff);
+    /// ```
+    pub fn backoff(&self, failed_attempts: u32) -> std::time::Duration {
+        let exponent: u32 = failed_attempts.saturating_sub(1).min(31);
+        let backoff: std::time::Duration = self.initial_backoff.saturating_mul(1 << exponent).min(self.max_backoff);
+        backoff.mul_f64(1.0 - jitter() / 2.0)
+    }
+}
+
+/// A random fraction in `[0, 1)`, drawn from the random keys of the standard library's hasher.
+fn jitter() -> f64 {
+    use std::hash::{BuildHasher, Hasher};
+    let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+    hasher.write_u128(std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap_or_default().as_nanos());
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// How a request is handled after a response status.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StatusClass {
+    /// The response is the content requested.
+    Success,
+    /// The server is busy, rate limited or temporarily failing; the request is retried.
+    Transient,
+    /// The request cannot succeed as it is, such as a request for an unknown identifier.
+    Permanent,
+}
+
+impl StatusClass {
+    /// Classify an HTTP status code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use utils::web::StatusClass;
+    ///
+    /// assert_eq!(StatusClass::classify(awc::http::StatusCode::OK), StatusClass::Success);
+    /// assert_eq!(StatusClass::classify(awc::http::StatusCode::TOO_MANY_REQUESTS), StatusClass::Transient);
+    /// assert_eq!(StatusClass::classify(awc::http::StatusCode::SERVICE_UNAVAILABLE), StatusClass::Transient);
+    /// assert_eq!(StatusClass::classify(awc::http::StatusCode::BAD_REQUEST), StatusClass::Permanent);
+    /// ```
+    pub fn classify(status: awc::http::StatusCode) -> StatusClass {
+        match status.as_u16() {
+            200..=299 => StatusClass::Success,
+            408 | 425 | 429 | 500 | 502 | 503 | 504 => StatusClass::Transient,
+            _ => StatusClass::Permanent,
+        }
+    }
+}
+
+/// A request that failed, permanently or after every attempt allowed by its retry policy.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FetchError {
+    /// The server responded with a status that is not a success, such as 404, or 503 on every attempt.
+    Status { url: String, status: u16, body: String, attempts: u32 },
+    /// Every attempt took longer than the retry policy's timeout.
+    Timeout { url: String, attempts: u32 },
+    /// The server could not be connected to, or the connection failed while sending the request or reading the response.
+    Connection { url: String, message: String, attempts: u32 },
+    /// The daily limit of the service was used up before an attempt was made (see `quota::global()`).
+    QuotaExceeded { url: String, error: crate::quota::QuotaExceededError, attempts: u32 },
+}
+
+impl FetchError {
+    pub fn get_url(&self) -> &str {
+        match self {
+            FetchError::Status { url, .. }
+            | FetchError::Timeout { url, .. }
+            | FetchError::Connection { url, .. }
+            | FetchError::QuotaExceeded { url, .. } => url,
+        }
+    }
+
+    pub fn get_status(&self) -> Option<u16> {
+        match self {
+            FetchError::Status { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    pub fn get_attempts(&self) -> u32 {
+        match self {
+            FetchError::Status { attempts, .. }
+            | FetchError::Timeout { attempts, .. }
+            | FetchError::Connection { attempts, .. }
+            | FetchError::QuotaExceeded { attempts, .. } => *attempts,
+        }
+    }
+
+    /// Whether the request failed for a reason that may pass, so that it may succeed later.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            FetchError::Status { status, .. } => awc::http::StatusCode::from_u16(*status)
+                .map(|status| StatusClass::classify(status) == StatusClass::Transient)
+                .unwrap_or(false),
+            FetchError::Timeout { .. } | FetchError::Connection { .. } => true,
+            // The limit is of the calendar day, so the request is not retried within a run.
+            FetchError::QuotaExceeded { .. } => false,
+        }
+    }
+
+    /// The server's explanation of a failed status: the `error` of a JSON body, as EnsEMBL and the UCSC Genome Browser
+    /// respond with, or otherwise the body itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let error = utils::web::FetchError::Status {
+    ///     url: String::from("https://rest.ensembl.org/lookup/id/ENSG0"),
+    ///     status: 400,
+    ///     body: String::from("{\"error\":\"ID 'ENSG0' not found\"}"),
+    ///     attempts: 1,
+    /// };
+    /// assert_eq!(error.get_message(), "ID 'ENSG0' not found");
+    /// assert!(!error.is_transient());
+    /// ```
+    pub fn get_message(&self) -> String {
+        match self {
+            FetchError::Status { body, .. } => serde_json::from_str::<serde_json::Value>(body)
+                .ok()
+                .and_then(|json| json["error"].as_str().map(String::from))
+                .unwrap_or_else(|| String::from(body.trim())),
+            FetchError::Timeout { .. } => String::from("the request timed out"),
+            FetchError::Connection { message, .. } => message.clone(),
+            FetchError::QuotaExceeded { error, .. } => error.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::Status { url, status, attempts, .. } => {
+                write!(f, "{url} responded with HTTP status {status} after {attempts} attempt(s): {}", self.get_message())
+            }
+            FetchError::Timeout { url, attempts } => write!(f, "{url} timed out after {attempts} attempt(s)"),
+            FetchError::Connection { url, message, attempts } => write!(f, "{url} failed after {attempts} attempt(s): {message}"),
+            FetchError::QuotaExceeded { url, error, attempts } => write!(f, "{url} was not requested after {attempts} attempt(s): {error}"),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+/// Send a request until it succeeds, returning the response body, or until it fails permanently or runs out of attempts.
+///
+/// `send` sends the request to `url` with the timeout it is given. Each attempt waits for `rate_limit::global()` to allow it,
+/// and the headers of each response adapt its pace. Each attempt to a service with a daily limit is counted in
+/// `quota::global()`, and none is made once the limit is used up. Transient statuses (see `StatusClass`), connection failures and timeouts
+/// are retried after `RetryPolicy::backoff`; any other status is returned as a `FetchError::Status` without retrying.
+///
+/// # Example
+///
+/// ```
+/// let runtime = actix_web::rt::Runtime::new().unwrap();
+/// let policy = utils::web::RetryPolicy { max_attempts: 2, ..utils::web::DEFAULT_RETRY_POLICY };
+/// let error: utils::web::FetchError = runtime.block_on(async {
+///     let client: awc::Client = awc::Client::default();
+///     let url: &str = "http://localhost:1/";
+///     utils::web::fetch(url, &policy, |timeout| client.get(url).timeout(timeout).send()).await.unwrap_err()
+/// });
+/// assert!(error.is_transient());
+/// assert_eq!(error.get_attempts(), 2);
+/// ```
+pub async fn fetch<F>(url: &str, policy: &RetryPolicy, send: F) -> Result<actix_web::web::Bytes, FetchError>
+where
+    F: Fn(std::time::Duration) -> awc::SendClientRequest,
+{
+    let mut attempts: u32 = 0;
+    loop {
+        crate::rate_limit::global().acquire(url).await;
+        reserve_quota(url, attempts)?;
+        attempts += 1;
+
+        let attempt = async {
+            let mut client_response = send(policy.timeout).await.map_err(|error| error.to_string())?;
+            crate::rate_limit::global().observe(url, client_response.headers());
+            let status: awc::http::StatusCode = client_response.status();
+            let response_body = client_response.body().limit(RESPONSE_BODY_LIMIT).await.map_err(|error| error.to_string())?;
+            Ok::<_, String>((status, response_body))
+        };
+
+        let error: FetchError = match actix_web::rt::time::timeout(policy.timeout, attempt).await {
+            Ok(Ok((status, response_body))) => match StatusClass::classify(status) {
+                StatusClass::Success => return Ok(response_body),
+                class => {
+                    let error = FetchError::Status {
+                        url: String::from(url),
+                        status: status.as_u16(),
+                        body: String::from_utf8_lossy(&response_body).into_owned(),
+                        attempts,
+                    };
+                    if class == StatusClass::Permanent {
+                        return Err(error);
+                    }
+                    error
+                }
+            },
+            Ok(Err(message)) => FetchError::Connection { url: String::from(url), message, attempts },
+            Err(_) => FetchError::Timeout { url: String::from(url), attempts },
+        };
+
+        if attempts >= policy.max_attempts {
+            return Err(error);
+        }
+        let backoff: std::time::Duration = policy.backoff(attempts);
+        eprintln!("{{\"url\": \"{}\", \"attempt\": {}, \"retry_in_ms\": {}, \"error\": \"{}\"}}", url, attempts, backoff.as_millis(), error);
+        actix_web::rt::time::sleep(backoff).await;
+    }
+}
+
+/// Count an attempt of a request in `quota::global()`, if its service is counted (see `quota::service`), after a number of attempts.
+///
+/// A ledger that cannot be read or written is reported, and the attempt is made.
+fn reserve_quota(url: &str, attempts: u32) -> Result<(), FetchError> {
+    let Some((service, limit)) = crate::quota::service(url) else {
+        return Ok(());
+    };
+    match crate::quota::global().reserve(service, limit) {
+        Ok(_) => Ok(()),
+        Err(error) => match error.downcast::<crate::quota::QuotaExceededError>() {
+            Ok(error) => {
+                eprintln!("{{\"url\": \"{}\", \"error\": \"{}\"}}", url, error);
+                Err(FetchError::QuotaExceeded { url: String::from(url), error: *error, attempts })
+            }
+            Err(error) => {
+                eprintln!("{{\"file\": \"{}\", \"error\": \"{}\"}}", crate::quota::global().get_path().display(), error);
+                Ok(())
+            }
+        },
+    }
+}
+
+/// Build and return an Actix Web Client trusting the X.509 certificates of certificate authorities in files, PEM or DER encoded.
+///
+/// Only the certificates of the files are trusted; see `TlsConfig` to trust the system's trust store or the bundled
+/// webpki roots, or to authenticate with a client certificate.
 /// 
 /// # Example
 ///  
@@ -141,70 +492,287 @@ pub async fn get_html_body(client: &awc::Client, url: &str) -> Result<String, Bo
 ///     first_certificate_file.write_all(first_certificate_contents.as_bytes());
 ///     second_certificate_file.write_all(second_certificate_contents.as_bytes());
 ///     let certificate_files: Vec<&std::path::Path> = vec![first_certificate_file.path(), second_certificate_file.path()];
-///     let client: awc::Client = utils::web::new_client(certificate_files);
+///     let client: awc::Client = utils::web::new_client(certificate_files).unwrap();
 ///     let response = client.get("https://api.genome.ucsc.edu/getData/sequence?genome=hg38;chrom=chrX;start=1;end=2").send().await.unwrap();
 ///     first_certificate_file.close();
 ///     second_certificate_file.close();
 ///     response.status()
 /// }), 200);
 /// ```
-pub fn new_client<'a, T>(certificate_files: T) -> awc::Client
+pub fn new_client<'a, T>(certificate_files: T) -> Result<awc::Client, TlsConfigError>
 where
     T: IntoIterator<Item = &'a std::path::Path>,
-    T::IntoIter: 'a,
 {
-    let mut root_store: rustls::RootCertStore = rustls::RootCertStore::empty();
-
-    //  For each certificate file provided,
-    for certificate_file in certificate_files {
-        //  Read the file,
-        match std::fs::read(certificate_file) {
-            Ok(file_contents) => {
-                //  If the file opens successfully,
-                //  Add the file contents to a buffer and instantiates a rustls certificate object.
-                let mut buffer: std::io::BufReader<&[u8]> =
-                    std::io::BufReader::new(file_contents.as_slice());
-                let certificates: Vec<Vec<u8>> = match rustls_pemfile::certs(&mut buffer) {
-                    Ok(certificates) => certificates,
-                    Err(error) => {
-                        eprintln!("{{\"error\": \"{}\"}}", error);
-                        std::process::exit(1);
-                    }
-                };
-                //  For each individual certificate,
-                for certificate_bytes in certificates {
-                    //  Add the certificate to the root store.
-                    let certificate: rustls::Certificate = rustls::Certificate(certificate_bytes);
-                    let add_to_root_store_result = root_store.add(&certificate);
-                    if add_to_root_store_result.is_err() {
-                        eprintln!("{{\"error\": \"{:#?}\"}}", add_to_root_store_result.err());
-                        std::process::exit(1);
-                    }
-                }
+    certificate_files
+        .into_iter()
+        .fold(TlsConfig::new(), |config, certificate_file| config.with_certificate_file(certificate_file))
+        .client()
+}
+
+/// Where the certificates of trusted certificate authorities come from, besides certificate files.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum RootCertificates {
+    /// Only the certificate files are trusted.
+    #[default]
+    None,
+    /// The operating system's trust store.
+    System,
+    /// The Mozilla root certificates bundled by the `webpki-roots` crate.
+    Webpki,
+}
+
+impl std::str::FromStr for RootCertificates {
+    type Err = std::io::Error;
+
+    fn from_str(value: &str) -> Result<RootCertificates, std::io::Error> {
+        match value {
+            "none" => Ok(RootCertificates::None),
+            "system" => Ok(RootCertificates::System),
+            "webpki" => Ok(RootCertificates::Webpki),
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("{value} is not a source of root certificates (none, system, webpki)"),
+            )),
+        }
+    }
+}
+
+/// A certificate, key or trust store that cannot be read or used.
+#[derive(Debug)]
+pub enum TlsConfigError {
+    /// The file cannot be read.
+    Read { path: std::path::PathBuf, error: std::io::Error },
+    /// The file holds no certificate, or no private key.
+    Empty { path: std::path::PathBuf },
+    /// The certificate, or private key, of the file is not valid.
+    Invalid { path: std::path::PathBuf, message: String },
+    /// The operating system's trust store cannot be loaded.
+    SystemRoots(std::io::Error),
+    /// No certificate authority is trusted, so no server could be verified.
+    NoRootCertificates,
+}
+
+impl std::fmt::Display for TlsConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TlsConfigError::Read { path, error } => write!(f, "{} cannot be read: {}", path.display(), error),
+            TlsConfigError::Empty { path } => write!(f, "{} holds no certificate or private key", path.display()),
+            TlsConfigError::Invalid { path, message } => write!(f, "{} is not valid: {}", path.display(), message),
+            TlsConfigError::SystemRoots(error) => write!(f, "the system's trust store cannot be loaded: {}", error),
+            TlsConfigError::NoRootCertificates => write!(f, "no certificate authority is trusted; supply a certificate or trust the system's or webpki's root certificates"),
+        }
+    }
+}
+
+impl std::error::Error for TlsConfigError {}
+
+/// The TLS configuration of an Actix Web Client: the certificate authorities it trusts, and its client certificate, if any.
+///
+/// Certificates and keys are read from PEM or DER encoded files.
+///
+/// # Example
+///
+/// ```
+/// use utils::web::{RootCertificates, TlsConfig, TlsConfigError};
+///
+/// let runtime = actix_web::rt::Runtime::new().unwrap();
+/// runtime.block_on(async {
+///     // The bundled webpki roots, as well as any certificate files.
+///     assert!(TlsConfig::new().with_root_certificates(RootCertificates::Webpki).client().is_ok());
+///
+///     // A client that trusts no certificate authority is an error.
+///     assert!(matches!(TlsConfig::new().client(), Err(TlsConfigError::NoRootCertificates)));
+///
+///     // As is a certificate file without certificates.
+///     let empty_file: tempfile::NamedTempFile = tempfile::NamedTempFile::new().unwrap();
+///     assert!(matches!(TlsConfig::new().with_certificate_file(empty_file.path()).client(), Err(TlsConfigError::Empty { .. })));
+/// });
+///
+/// // Lines outside of the PEM sections of a certificate file, such as comments or OpenSSL's bag attributes, are skipped.
+/// let commented_certificate_contents: &str = "## Go Daddy Root Certificate Authority - G2
+/// Bag Attributes
+///     friendlyName: Go Daddy Root Certificate Authority - G2
+/// -----BEGIN CERTIFICATE-----
+/// MIIDxTCCAq2gAwIBAgIBADANBgkqhkiG9w0BAQsFADCBgzELMAkGA1UEBhMCVVMx
+/// EDAOBgNVBAgTB0FyaXpvbmExEzARBgNVBAcTClNjb3R0c2RhbGUxGjAYBgNVBAoT
+/// EUdvRGFkZHkuY29tLCBJbmMuMTEwLwYDVQQDEyhHbyBEYWRkeSBSb290IENlcnRp
+/// ZmljYXRlIEF1dGhvcml0eSAtIEcyMB4XDTA5MDkwMTAwMDAwMFoXDTM3MTIzMTIz
+/// NTk1OVowgYMxCzAJBgNVBAYTAlVTMRAwDgYDVQQIEwdBcml6b25hMRMwEQYDVQQH
+/// EwpTY290dHNkYWxlMRowGAYDVQQKExFHb0RhZGR5LmNvbSwgSW5jLjExMC8GA1UE
+/// AxMoR28gRGFkZHkgUm9vdCBDZXJ0aWZpY2F0ZSBBdXRob3JpdHkgLSBHMjCCASIw
+/// DQYJKoZIhvcNAQEBBQADggEPADCCAQoCggEBAL9xYgjx+lk09xvJGKP3gElY6SKD
+/// E6bFIEMBO4Tx5oVJnyfq9oQbTqC023CYxzIBsQU+B07u9PpPL1kwIuerGVZr4oAH
+/// /PMWdYA5UXvl+TW2dE6pjYIT5LY/qQOD+qK+ihVqf94Lw7YZFAXK6sOoBJQ7Rnwy
+/// DfMAZiLIjWltNowRGLfTshxgtDj6AozO091GB94KPutdfMh8+7ArU6SSYmlRJQVh
+/// GkSBjCypQ5Yj36w6gZoOKcUcqeldHraenjAKOc7xiID7S13MMuyFYkMlNAJWJwGR
+/// tDtwKj9useiciAF9n9T521NtYJ2/LOdYq7hfRvzOxBsDPAnrSTFcaUaz4EcCAwEA
+/// AaNCMEAwDwYDVR0TAQH/BAUwAwEB/zAOBgNVHQ8BAf8EBAMCAQYwHQYDVR0OBBYE
+/// FDqahQcQZyi27/a9BUFuIMGU2g/eMA0GCSqGSIb3DQEBCwUAA4IBAQCZ21151fmX
+/// WWcDYfF+OwYxdS2hII5PZYe096acvNjpL9DbWu7PdIxztDhC2gV7+AJ1uP2lsdeu
+/// 9tfeE8tTEH6KRtGX+rcuKxGrkLAngPnon1rpN5+r5N9ss4UXnT3ZJE95kTXWXwTr
+/// gIOrmgIttRD02JDHBHNA7XIloKmf7J6raBKZV8aPEjoJpL1E/QYVN8Gb5DKj7Tjo
+/// 2GTzLH4U/ALqn83/B2gX2yKQOC16jdFU8WnjXzPKej17CuPKf1855eJ1usV2GDPO
+/// LPAvTK33sefOT6jEm0pUBsV/fdUID+Ic/n4XuKxe9tQWskMJDE32p2u0mYRlynqI
+/// 4uJEvlz36hz1
+/// -----END CERTIFICATE-----
+/// ";
+/// let mut commented_file: tempfile::NamedTempFile = tempfile::NamedTempFile::new().unwrap();
+/// std::io::Write::write_all(&mut commented_file, commented_certificate_contents.as_bytes()).unwrap();
+/// assert!(TlsConfig::new().with_certificate_file(commented_file.path()).rustls_config().is_ok());
+///
+/// // A commented file without certificates holds nothing to trust.
+/// let mut comment_file: tempfile::NamedTempFile = tempfile::NamedTempFile::new().unwrap();
+/// std::io::Write::write_all(&mut comment_file, b"## No certificates\n").unwrap();
+/// assert!(matches!(TlsConfig::new().with_certificate_file(comment_file.path()).rustls_config(), Err(TlsConfigError::Empty { .. })));
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TlsConfig {
+    certificate_files: Vec<std::path::PathBuf>,
+    root_certificates: RootCertificates,
+    client_certificate: Option<(std::path::PathBuf, std::path::PathBuf)>,
+}
+
+impl TlsConfig {
+    /// A configuration trusting no certificate authority, without a client certificate.
+    pub fn new() -> TlsConfig {
+        TlsConfig::default()
+    }
+
+    /// Trust the certificates of certificate authorities in a file: one or more PEM encoded certificates, or a DER encoded certificate.
+    pub fn with_certificate_file(mut self, path: &std::path::Path) -> TlsConfig {
+        self.certificate_files.push(path.to_path_buf());
+        self
+    }
+
+    /// Trust the operating system's trust store, or the bundled webpki roots, as well as the certificate files.
+    pub fn with_root_certificates(mut self, root_certificates: RootCertificates) -> TlsConfig {
+        self.root_certificates = root_certificates;
+        self
+    }
+
+    /// Authenticate the client to servers that ask for a client certificate (mutual TLS): a certificate chain, the client's
+    /// certificate first, and its private key (PKCS#8, PKCS#1 or SEC1).
+    pub fn with_client_certificate(mut self, certificate_file: &std::path::Path, key_file: &std::path::Path) -> TlsConfig {
+        self.client_certificate = Some((certificate_file.to_path_buf(), key_file.to_path_buf()));
+        self
+    }
+
+    pub fn get_certificate_files(&self) -> &[std::path::PathBuf] {
+        &self.certificate_files
+    }
+
+    pub fn get_root_certificates(&self) -> RootCertificates {
+        self.root_certificates
+    }
+
+    /// The rustls client configuration.
+    pub fn rustls_config(&self) -> Result<rustls::ClientConfig, TlsConfigError> {
+        let mut root_store: rustls::RootCertStore = rustls::RootCertStore::empty();
+
+        //  Add the certificates of each certificate file to the root store.
+        for certificate_file in self.certificate_files.iter() {
+            for certificate in read_certificates(certificate_file)? {
+                root_store.add(&certificate).map_err(|error| TlsConfigError::Invalid {
+                    path: certificate_file.clone(),
+                    message: error.to_string(),
+                })?;
             }
-            Err(error) => {
-                eprintln!(
-                    "{{\"file\": \"{}\", \"error\":\"{}\"}}",
-                    certificate_file.display(), error
-                );
-                std::process::exit(1);
+        }
+
+        match self.root_certificates {
+            RootCertificates::None => (),
+            RootCertificates::System => {
+                let certificates: Vec<Vec<u8>> = rustls_native_certs::load_native_certs()
+                    .map_err(TlsConfigError::SystemRoots)?
+                    .into_iter()
+                    .map(|certificate| certificate.0)
+                    .collect();
+                //  Certificates of the trust store that rustls cannot parse are skipped.
+                root_store.add_parsable_certificates(&certificates);
+            }
+            RootCertificates::Webpki => {
+                root_store.add_server_trust_anchors(webpki_roots::TLS_SERVER_ROOTS.0.iter().map(|trust_anchor| {
+                    rustls::OwnedTrustAnchor::from_subject_spki_name_constraints(
+                        trust_anchor.subject,
+                        trust_anchor.spki,
+                        trust_anchor.name_constraints,
+                    )
+                }));
+            }
+        }
+
+        if root_store.is_empty() {
+            return Err(TlsConfigError::NoRootCertificates);
+        }
+
+        let builder = rustls::ClientConfig::builder().with_safe_defaults().with_root_certificates(root_store);
+        match &self.client_certificate {
+            Some((certificate_file, key_file)) => {
+                let certificates: Vec<rustls::Certificate> = read_certificates(certificate_file)?;
+                let key: rustls::PrivateKey = read_private_key(key_file)?;
+                builder.with_single_cert(certificates, key).map_err(|error| TlsConfigError::Invalid {
+                    path: key_file.clone(),
+                    message: error.to_string(),
+                })
             }
+            None => Ok(builder.with_no_client_auth()),
         }
     }
 
-    //  Configure rustls.
-    let rustls_config: rustls::ClientConfig = rustls::ClientConfig::builder()
-        .with_safe_defaults()
-        .with_root_certificates(root_store)
-        .with_no_client_auth();
+    /// Build and return an Actix Web Client with the configuration.
+    pub fn client(&self) -> Result<awc::Client, TlsConfigError> {
+        //  Instantiate a thread-safe atomically reference-counting pointer to share the client configuration with the Actix Web Client Rustls Connector and Client
+        let client_tls_config: std::sync::Arc<rustls::ClientConfig> = std::sync::Arc::new(self.rustls_config()?);
+
+        // Return an Actix Web Client object.
+        Ok(awc::Client::builder()
+            .add_default_header((awc::http::header::USER_AGENT, "utils/0,1"))
+            .connector(awc::Connector::new().rustls(std::sync::Arc::clone(&client_tls_config)))
+            .finish())
+    }
+}
 
-    //  Instantiate a thread-safe atomically reference-counting pointer to share the client configuration with the Actix Web Client Rustls Connector and Client
-    let client_tls_config: std::sync::Arc<rustls::ClientConfig> =
-        std::sync::Arc::new(rustls_config);
+/// Read a file's contents, which are DER encoded if they begin with an ASN.1 sequence (0x30), and PEM encoded otherwise.
+///
+/// Lines of a PEM file outside of its PEM sections, such as comments or OpenSSL's `Bag Attributes`, are skipped when it is parsed.
+fn read_encoded(path: &std::path::Path) -> Result<(Vec<u8>, bool), TlsConfigError> {
+    let contents: Vec<u8> = std::fs::read(path).map_err(|error| TlsConfigError::Read { path: path.to_path_buf(), error })?;
+    let is_der: bool = contents.first() == Some(&0x30);
+    Ok((contents, is_der))
+}
+
+/// Read the certificates of a file: every certificate of a PEM file, or the certificate of a DER file.
+fn read_certificates(path: &std::path::Path) -> Result<Vec<rustls::Certificate>, TlsConfigError> {
+    let (contents, is_der) = read_encoded(path)?;
+    let certificates: Vec<Vec<u8>> = match is_der {
+        true => vec![contents],
+        false => rustls_pemfile::certs(&mut contents.as_slice()).map_err(|error| TlsConfigError::Invalid {
+            path: path.to_path_buf(),
+            message: error.to_string(),
+        })?,
+    };
+    match certificates.is_empty() {
+        true => Err(TlsConfigError::Empty { path: path.to_path_buf() }),
+        false => Ok(certificates.into_iter().map(rustls::Certificate).collect()),
+    }
+}
+
+/// Read the private key of a file: the first key of a PEM file, or the key of a DER file.
+fn read_private_key(path: &std::path::Path) -> Result<rustls::PrivateKey, TlsConfigError> {
+    let (contents, is_der) = read_encoded(path)?;
+    if is_der {
+        return Ok(rustls::PrivateKey(contents));
+    }
 
-    // Return an Actix Web Client object.
-    awc::Client::builder()
-        .add_default_header((awc::http::header::USER_AGENT, "utils/0,1"))
-        .connector(awc::Connector::new().rustls(std::sync::Arc::clone(&client_tls_config)))
-        .finish()
+    let items: Vec<rustls_pemfile::Item> = rustls_pemfile::read_all(&mut contents.as_slice()).map_err(|error| TlsConfigError::Invalid {
+        path: path.to_path_buf(),
+        message: error.to_string(),
+    })?;
+    items
+        .into_iter()
+        .find_map(|item| match item {
+            rustls_pemfile::Item::PKCS8Key(key) | rustls_pemfile::Item::RSAKey(key) | rustls_pemfile::Item::ECKey(key) => Some(rustls::PrivateKey(key)),
+            _ => None,
+        })
+        .ok_or_else(|| TlsConfigError::Empty { path: path.to_path_buf() })
 }
@@ -0,0 +1,176 @@
+//! MIT license.
+
+/// An external database EnsEMBL cross-references genes, transcripts and translations to.
+///
+/// Databases other than those named are kept by their EnsEMBL database name.
+///
+/// # Example
+///
+/// ```
+/// let database: utils::xref::ExternalDatabase = "EntrezGene".parse().unwrap();
+/// assert_eq!(database, utils::xref::ExternalDatabase::NcbiGene);
+/// assert_eq!(database.get_column_name(), Some("ncbi_gene_id"));
+/// assert_eq!("ncbi_gene_id".parse::<utils::xref::ExternalDatabase>().unwrap(), database);
+/// assert_eq!(database.to_string(), "EntrezGene");
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ExternalDatabase {
+    /// HUGO Gene Nomenclature Committee symbols, `HGNC`.
+    Hgnc,
+    /// NCBI Gene identifiers, `EntrezGene`.
+    NcbiGene,
+    /// Online Mendelian Inheritance in Man gene entries, `MIM_GENE`.
+    Omim,
+    /// Reviewed UniProtKB entries, `Uniprot/SWISSPROT`.
+    UniprotSwissprot,
+    /// Unreviewed UniProtKB entries, `Uniprot/SPTREMBL`.
+    UniprotTrembl,
+    /// NCBI Reference Sequence transcripts, `RefSeq_mRNA`.
+    RefSeqMrna,
+    /// NCBI Reference Sequence proteins, `RefSeq_peptide`.
+    RefSeqPeptide,
+    /// Any other database, by its EnsEMBL database name.
+    Other(String),
+}
+
+impl ExternalDatabase {
+    /// The databases with a CSV column name.
+    pub const NAMED: [ExternalDatabase; 7] = [
+        ExternalDatabase::Hgnc,
+        ExternalDatabase::NcbiGene,
+        ExternalDatabase::Omim,
+        ExternalDatabase::UniprotSwissprot,
+        ExternalDatabase::UniprotTrembl,
+        ExternalDatabase::RefSeqMrna,
+        ExternalDatabase::RefSeqPeptide,
+    ];
+
+    /// The database's name in EnsEMBL, as in the `dbname` of `/xrefs/id` and its `external_db` parameter.
+    pub fn get_ensembl_name(&self) -> &str {
+        match self {
+            ExternalDatabase::Hgnc => "HGNC",
+            ExternalDatabase::NcbiGene => "EntrezGene",
+            ExternalDatabase::Omim => "MIM_GENE",
+            ExternalDatabase::UniprotSwissprot => "Uniprot/SWISSPROT",
+            ExternalDatabase::UniprotTrembl => "Uniprot/SPTREMBL",
+            ExternalDatabase::RefSeqMrna => "RefSeq_mRNA",
+            ExternalDatabase::RefSeqPeptide => "RefSeq_peptide",
+            ExternalDatabase::Other(name) => name,
+        }
+    }
+
+    /// The name of the database's column in a CSV file of genes, or `None` for other databases.
+    pub fn get_column_name(&self) -> Option<&'static str> {
+        match self {
+            ExternalDatabase::Hgnc => Some("hgnc"),
+            ExternalDatabase::NcbiGene => Some("ncbi_gene_id"),
+            ExternalDatabase::Omim => Some("omim"),
+            ExternalDatabase::UniprotSwissprot => Some("uniprot_swissprot"),
+            ExternalDatabase::UniprotTrembl => Some("uniprot_trembl"),
+            ExternalDatabase::RefSeqMrna => Some("refseq_mrna"),
+            ExternalDatabase::RefSeqPeptide => Some("refseq_peptide"),
+            ExternalDatabase::Other(_) => None,
+        }
+    }
+}
+
+impl std::str::FromStr for ExternalDatabase {
+    type Err = std::convert::Infallible;
+
+    /// Parse a database from its EnsEMBL name or its column name.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let database: Option<&ExternalDatabase> = ExternalDatabase::NAMED.iter().find(|database| {
+            database.get_ensembl_name() == name || database.get_column_name() == Some(name)
+        });
+        Ok(database.cloned().unwrap_or_else(|| ExternalDatabase::Other(String::from(name))))
+    }
+}
+
+impl std::fmt::Display for ExternalDatabase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.get_ensembl_name())
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for ExternalDatabase {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let name: String = serde::de::Deserialize::deserialize(deserializer)?;
+        match name.parse::<ExternalDatabase>() {
+            Ok(database) => Ok(database),
+            Err(error) => match error {},
+        }
+    }
+}
+
+/// A cross-reference of an EnsEMBL gene, or of its transcripts and translations, to an external database,
+/// as returned by EnsEMBL's `/xrefs/id`.
+///
+/// # Example
+///
+/// ```
+/// let json: &str = r#"[{"dbname": "HGNC", "primary_id": "HGNC:11858", "display_id": "TSPAN6", "synonyms": ["TM4SF6"],
+///                       "description": "tetraspanin 6", "info_type": "DIRECT", "db_display_name": "HGNC Symbol"},
+///                      {"dbname": "ArrayExpress", "primary_id": "ENSG00000000003", "display_id": "ENSG00000000003"}]"#;
+/// let xrefs: Vec<utils::xref::ExternalReference> = serde_json::from_str(json).unwrap();
+/// assert_eq!(xrefs[0].get_database(), &utils::xref::ExternalDatabase::Hgnc);
+/// assert_eq!(xrefs[0].get_primary_id(), "HGNC:11858");
+/// assert_eq!(xrefs[0].get_synonyms(), ["TM4SF6"]);
+/// assert_eq!(xrefs[1].get_database().get_column_name(), None);
+/// ```
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
+pub struct ExternalReference {
+    #[serde(rename = "dbname")]
+    database: ExternalDatabase,
+    primary_id: String,
+    #[serde(default)]
+    display_id: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    synonyms: Vec<String>,
+    #[serde(default)]
+    info_type: Option<String>,
+}
+
+impl ExternalReference {
+    pub fn new(database: ExternalDatabase, primary_id: &str, display_id: &str) -> ExternalReference {
+        ExternalReference {
+            database,
+            primary_id: String::from(primary_id),
+            display_id: String::from(display_id),
+            description: None,
+            synonyms: Vec::new(),
+            info_type: None,
+        }
+    }
+
+    pub fn get_database(&self) -> &ExternalDatabase {
+        &self.database
+    }
+
+    /// The identifier in the external database, such as `HGNC:11858` or `7105`.
+    pub fn get_primary_id(&self) -> &str {
+        &self.primary_id
+    }
+
+    /// The name the external database displays, such as the symbol `TSPAN6`.
+    pub fn get_display_id(&self) -> &str {
+        &self.display_id
+    }
+
+    pub fn get_description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn get_synonyms(&self) -> &[String] {
+        &self.synonyms
+    }
+
+    /// How EnsEMBL made the cross-reference, such as `DIRECT`, `DEPENDENT` or `SEQUENCE_MATCH`.
+    pub fn get_info_type(&self) -> Option<&str> {
+        self.info_type.as_deref()
+    }
+}